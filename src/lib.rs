@@ -1,4 +1,5 @@
 #![cfg(unix)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 use core::{fmt, time::Duration};
 
@@ -16,71 +17,28 @@ pub use bsd_errnos::Errno;
 #[cfg(target_os = "linux")]
 pub use linux_syscalls::Errno;
 
+mod instant;
 pub mod raw;
+#[cfg(any(
+    target_os = "linux",
+    target_os = "openbsd",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+mod uptime;
 
-#[allow(clippy::needless_doctest_main)]
-/// A measurement of a monotonically nondecreasing clock.
-/// Opaque and useful only with [`Duration`].
-///
-/// Instants are always guaranteed, barring [platform bugs], to be no less than any previously
-/// measured instant when created, and are often useful for tasks such as measuring
-/// benchmarks or timing how long an operation takes.
-///
-/// Note, however, that instants are **not** guaranteed to be **steady**. In other
-/// words, each tick of the underlying clock might not be the same length (e.g.
-/// some seconds may be longer than others). An instant may jump forwards or
-/// experience time dilation (slow down or speed up), but it will never go
-/// backwards.
-///
-/// Instants are opaque types that can only be compared to one another. There is
-/// no method to get "the number of seconds" from an instant. Instead, it only
-/// allows measuring the duration between two instants (or comparing two
-/// instants).
-///
-/// The size of an `Instant` struct may vary depending on the target operating
-/// system.
-///
-/// Example:
-///
-/// ```no_run
-/// use unix_clock::Instant;
-/// use std::time::Duration;
-/// use std::thread::sleep;
-///
-/// fn main() {
-///    let now = Instant::now();
-///
-///    // we sleep for 2 seconds
-///    sleep(Duration::new(2, 0));
-///    // it prints '2'
-///    println!("{}", now.elapsed().as_secs());
-/// }
-/// ```
-///
-/// [platform bugs]: Instant#monotonicity
-///
-/// > Note: mathematical operations like `add` may panic if the underlying
-/// > structure cannot represent the new point in time.
-///
-/// ## Monotonicity
-///
-/// Guarantees are – under rare circumstances – broken by hardware, virtualization
-/// or operating system bugs. To work around these bugs and platforms not offering monotonic clocks
-/// [`duration_since`], [`elapsed`] and `sub` saturate to zero. In older Rust versions this
-/// lead to a panic instead. [`checked_duration_since`] can be used to detect and handle situations
-/// where monotonicity is violated, or `Instant`s are subtracted in the wrong order.
-///
-/// This workaround obscures programming errors where earlier and later instants are accidentally
-/// swapped. For this reason future rust versions may reintroduce panics.
-///
-/// [`duration_since`]: Instant::duration_since
-/// [`elapsed`]: Instant::elapsed
-/// [`checked_duration_since`]: Instant::checked_duration_since
-///
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Instant {
-    t: raw::Timespec,
-}
+pub use instant::Instant;
+#[cfg(any(
+    target_os = "linux",
+    target_os = "openbsd",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+pub use uptime::{proc_uptime, suspended_time, uptime, uptime_awake};
 
 #[allow(clippy::needless_doctest_main)]
 /// A measurement of the system clock, useful for talking to
@@ -160,196 +118,6 @@ pub struct SystemTime {
 #[derive(Clone, Debug)]
 pub struct SystemTimeError(Duration);
 
-impl Instant {
-    #[inline]
-    pub fn now() -> Self {
-        Instant {
-            t: raw::Timespec::now(raw::INSTANT_CLOCKID).unwrap(),
-        }
-    }
-
-    /// Returns the amount of time elapsed from another instant to this one,
-    /// or zero duration if that instant is later than this one.
-    ///
-    /// # Panics
-    ///
-    /// Previous rust versions panicked when `earlier` was later than `self`. Currently this
-    /// method saturates. Future versions may reintroduce the panic in some circumstances.
-    /// See [Monotonicity].
-    ///
-    /// [Monotonicity]: Instant#monotonicity
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use std::time::{Duration, Instant};
-    /// use std::thread::sleep;
-    ///
-    /// let now = Instant::now();
-    /// sleep(Duration::new(1, 0));
-    /// let new_now = Instant::now();
-    /// println!("{:?}", new_now.duration_since(now));
-    /// println!("{:?}", now.duration_since(new_now)); // 0ns
-    /// ```
-    #[must_use]
-    pub fn duration_since(&self, earlier: Instant) -> Duration {
-        self.checked_duration_since(earlier).unwrap_or_default()
-    }
-
-    /// Returns the amount of time elapsed from another instant to this one,
-    /// or None if that instant is later than this one.
-    ///
-    /// Due to [monotonicity bugs], even under correct logical ordering of the passed `Instant`s,
-    /// this method can return `None`.
-    ///
-    /// [monotonicity bugs]: Instant#monotonicity
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use std::time::{Duration, Instant};
-    /// use std::thread::sleep;
-    ///
-    /// let now = Instant::now();
-    /// sleep(Duration::new(1, 0));
-    /// let new_now = Instant::now();
-    /// println!("{:?}", new_now.checked_duration_since(now));
-    /// println!("{:?}", now.checked_duration_since(new_now)); // None
-    /// ```
-    #[must_use]
-    pub fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
-        self.t.sub_timespec(&earlier.t).ok()
-    }
-
-    /// Returns the amount of time elapsed from another instant to this one,
-    /// or zero duration if that instant is later than this one.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use std::time::{Duration, Instant};
-    /// use std::thread::sleep;
-    ///
-    /// let now = Instant::now();
-    /// sleep(Duration::new(1, 0));
-    /// let new_now = Instant::now();
-    /// println!("{:?}", new_now.saturating_duration_since(now));
-    /// println!("{:?}", now.saturating_duration_since(new_now)); // 0ns
-    /// ```
-    #[must_use]
-    pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
-        self.checked_duration_since(earlier).unwrap_or_default()
-    }
-
-    /// Returns the amount of time elapsed since this instant.
-    ///
-    /// # Panics
-    ///
-    /// Previous rust versions panicked when the current time was earlier than self. Currently this
-    /// method returns a Duration of zero in that case. Future versions may reintroduce the panic.
-    /// See [Monotonicity].
-    ///
-    /// [Monotonicity]: Instant#monotonicity
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use std::thread::sleep;
-    /// use std::time::{Duration, Instant};
-    ///
-    /// let instant = Instant::now();
-    /// let three_secs = Duration::from_secs(3);
-    /// sleep(three_secs);
-    /// assert!(instant.elapsed() >= three_secs);
-    /// ```
-    #[must_use]
-    pub fn elapsed(&self) -> Duration {
-        Instant::now() - *self
-    }
-
-    /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be represented as
-    /// `Instant` (which means it's inside the bounds of the underlying data structure), `None`
-    /// otherwise.
-    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
-        self.t.checked_add_duration(&duration).map(|t| Self { t })
-    }
-
-    /// Returns `Some(t)` where `t` is the time `self - duration` if `t` can be represented as
-    /// `Instant` (which means it's inside the bounds of the underlying data structure), `None`
-    /// otherwise.
-    pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
-        self.t.checked_sub_duration(&duration).map(|t| Self { t })
-    }
-}
-
-impl fmt::Debug for Instant {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Instant")
-            .field("secs", &self.t.secs())
-            .field("nsecs", &self.t.nsecs())
-            .finish()
-    }
-}
-
-impl core::ops::Add<Duration> for Instant {
-    type Output = Instant;
-
-    /// # Panics
-    ///
-    /// This function may panic if the resulting point in time cannot be represented by the
-    /// underlying data structure. See [`Instant::checked_add`] for a version without panic.
-    fn add(self, other: Duration) -> Instant {
-        self.checked_add(other)
-            .expect("overflow when adding duration to instant")
-    }
-}
-
-impl core::ops::AddAssign<Duration> for Instant {
-    fn add_assign(&mut self, other: Duration) {
-        *self = *self + other;
-    }
-}
-
-impl core::ops::Sub<Duration> for Instant {
-    type Output = Instant;
-
-    fn sub(self, other: Duration) -> Instant {
-        self.checked_sub(other)
-            .expect("overflow when subtracting duration from instant")
-    }
-}
-
-impl core::ops::SubAssign<Duration> for Instant {
-    fn sub_assign(&mut self, other: Duration) {
-        *self = *self - other;
-    }
-}
-
-impl core::ops::Sub<Instant> for Instant {
-    type Output = Duration;
-
-    /// Returns the amount of time elapsed from another instant to this one,
-    /// or zero duration if that instant is later than this one.
-    ///
-    /// # Panics
-    ///
-    /// Previous rust versions panicked when `other` was later than `self`. Currently this
-    /// method saturates. Future versions may reintroduce the panic in some circumstances.
-    /// See [Monotonicity].
-    ///
-    /// [Monotonicity]: Instant#monotonicity
-    fn sub(self, other: Instant) -> Duration {
-        self.duration_since(other)
-    }
-}
-
-impl From<Instant> for raw::Timespec {
-    #[inline]
-    fn from(value: Instant) -> Self {
-        value.t
-    }
-}
-
 impl SystemTime {
     /// An anchor in time which can be used to create new `SystemTime` instances or
     /// learn about where in time a `SystemTime` lies.
@@ -393,9 +161,14 @@ impl SystemTime {
     #[must_use]
     #[inline]
     pub fn now() -> Self {
-        Self {
-            t: raw::Timespec::now(raw::SYSTEM_TIME_CLOCKID).unwrap(),
-        }
+        Self::try_now().unwrap()
+    }
+
+    /// Returns the system time corresponding to "now", or the error returned reading the realtime
+    /// clock.
+    #[inline]
+    pub fn try_now() -> Result<Self, Errno> {
+        raw::Timespec::now(raw::SYSTEM_TIME_CLOCKID).map(|t| Self { t })
     }
 
     /// Returns the amount of time elapsed from an earlier point in time.
@@ -456,6 +229,28 @@ impl SystemTime {
         SystemTime::now().duration_since(*self)
     }
 
+    /// Returns the amount of time elapsed from [`UNIX_EPOCH`] to this system time.
+    ///
+    /// Returns an [`Err`] if `self` is before the epoch, and the error contains how far from the
+    /// epoch the time is.
+    ///
+    /// [`UNIX_EPOCH`]: SystemTime::UNIX_EPOCH
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// // 2020-01-01 00:00:00 UTC
+    /// let since_epoch = SystemTime::now().duration_since_epoch().unwrap();
+    /// assert!(since_epoch > Duration::from_secs(1_577_836_800));
+    /// ```
+    #[inline]
+    pub fn duration_since_epoch(&self) -> Result<Duration, SystemTimeError> {
+        self.duration_since(SystemTime::UNIX_EPOCH)
+    }
+
     /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be represented as
     /// `SystemTime` (which means it's inside the bounds of the underlying data structure), `None`
     /// otherwise.
@@ -478,6 +273,25 @@ impl SystemTime {
     pub fn set_clock(&self) -> Result<(), Errno> {
         self.t.set_clock()
     }
+
+    /// Returns the underlying realtime clock reading.
+    #[inline]
+    pub const fn as_timespec(&self) -> raw::Timespec {
+        self.t
+    }
+}
+
+impl SystemTimeError {
+    /// Returns the positive duration which represents how far forward the
+    /// second system time was from the first.
+    ///
+    /// A `SystemTimeError` is returned from the [`SystemTime::duration_since`]
+    /// and [`SystemTime::elapsed`] methods whenever the second system time
+    /// represents a point later in time than the `self` of the method call.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.0
+    }
 }
 
 impl core::ops::Add<Duration> for SystemTime {
@@ -529,3 +343,19 @@ impl From<SystemTime> for raw::Timespec {
         value.t
     }
 }
+
+/// Returns the current time of the [`ClockId::Realtime`] clock as the
+/// duration since the Unix epoch, saturating to zero if the clock is set
+/// before it.
+///
+/// [`ClockId::Realtime`]: raw::ClockId::Realtime
+///
+/// ```
+/// use std::time::Duration;
+///
+/// // 2020-01-01T00:00:00Z
+/// assert!(unix_clock::realtime_since_epoch().unwrap() > Duration::from_secs(1_577_836_800));
+/// ```
+pub fn realtime_since_epoch() -> Result<Duration, Errno> {
+    raw::Timespec::now(raw::ClockId::Realtime).map(|t| t.as_duration().unwrap_or_default())
+}
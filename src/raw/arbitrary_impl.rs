@@ -0,0 +1,32 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{ClockId, Timespec, NSEC_PER_SEC};
+
+/// Generates one of the known clocks.
+impl<'a> Arbitrary<'a> for ClockId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(ClockId::ALL).copied()
+    }
+}
+
+/// Generates normalized values only.
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use unix_clock::raw::{ClockId, Timespec};
+///
+/// let bytes: Vec<u8> = (0..=255).cycle().step_by(7).take(4096).collect();
+/// let mut u = Unstructured::new(&bytes);
+/// for _ in 0..256 {
+///     assert!(Timespec::arbitrary(&mut u).unwrap().is_normalized());
+///     ClockId::arbitrary(&mut u).unwrap();
+/// }
+/// ```
+impl<'a> Arbitrary<'a> for Timespec {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(
+            i64::arbitrary(u)?,
+            u.int_in_range(0..=NSEC_PER_SEC as u32 - 1)?,
+        ))
+    }
+}
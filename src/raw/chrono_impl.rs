@@ -0,0 +1,34 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+use super::Timespec;
+
+impl Timespec {
+    /// Converts a [`ClockId::Realtime`] reading to a UTC date and time, or
+    /// `None` if it is out of the range supported by chrono.
+    ///
+    /// [`ClockId::Realtime`]: super::ClockId::Realtime
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let t = Timespec::new(1_704_067_200, 0);
+    /// assert_eq!(t.to_datetime_utc(), Some(dt));
+    /// assert_eq!(Timespec::from_datetime(&dt), t);
+    /// assert_eq!(Timespec::MAX.to_datetime_utc(), None);
+    /// ```
+    #[inline]
+    pub fn to_datetime_utc(&self) -> Option<DateTime<Utc>> {
+        DateTime::<Utc>::from_timestamp(self.secs(), self.nsecs())
+    }
+
+    /// Converts a date and time to the corresponding [`ClockId::Realtime`]
+    /// reading. A leap second is carried into the next second.
+    ///
+    /// [`ClockId::Realtime`]: super::ClockId::Realtime
+    #[inline]
+    pub fn from_datetime<Tz: TimeZone>(dt: &DateTime<Tz>) -> Self {
+        Self::from_parts(dt.timestamp(), dt.timestamp_subsec_nanos() as u64)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Errno;
+
+use super::{ClockId, Timespec};
+
+/// A clock known at compile time, so that generic code can be written
+/// against it without hardcoding a [`ClockId`].
+///
+/// ```
+/// use unix_clock::raw::{Clock, ClockId, MonotonicClock, Timespec};
+///
+/// fn measure<C: Clock>(f: impl FnOnce()) -> std::time::Duration {
+///     let start = C::now().unwrap();
+///     f();
+///     C::now().unwrap().sub_timespec(&start).unwrap()
+/// }
+///
+/// let before = Timespec::now(ClockId::Monotonic).unwrap();
+/// let now = MonotonicClock::now().unwrap();
+/// assert!(now >= before && now <= Timespec::now(ClockId::Monotonic).unwrap());
+/// measure::<MonotonicClock>(|| ());
+/// ```
+pub trait Clock {
+    /// Returns the id of the clock.
+    fn id() -> ClockId;
+
+    /// Returns the current time of the clock.
+    #[inline]
+    fn now() -> Result<Timespec, Errno> {
+        Timespec::now(Self::id())
+    }
+}
+
+macro_rules! clock {
+    ($(#[$meta:meta])* $name:ident => $id:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name;
+
+        impl Clock for $name {
+            #[inline]
+            fn id() -> ClockId {
+                ClockId::$id
+            }
+        }
+    };
+}
+
+clock!(
+    /// The [`ClockId::Realtime`] clock.
+    RealtimeClock => Realtime
+);
+clock!(
+    /// The [`ClockId::Monotonic`] clock.
+    MonotonicClock => Monotonic
+);
+#[cfg(target_os = "linux")]
+clock!(
+    /// The [`ClockId::MonotonicRaw`] clock.
+    MonotonicRawClock => MonotonicRaw
+);
+#[cfg(target_os = "linux")]
+clock!(
+    /// The [`ClockId::Boottime`] clock.
+    BoottimeClock => Boottime
+);
@@ -0,0 +1,78 @@
+use core::fmt;
+
+use super::ClockId;
+use crate::Errno;
+
+/// A classification of the errors returned when setting a clock, see
+/// [`Timespec::set_clock_id`](super::Timespec::set_clock_id).
+///
+/// The kernel reports both an invalid clock and an out of range time with
+/// `EINVAL`, only `set_clock_id`, knowing the clock, tells them apart.
+///
+/// ```
+/// use unix_clock::{raw::{ClockError, ClockId, Timespec}, Errno};
+///
+/// assert_eq!(
+///     Timespec::zero().set_clock_id(ClockId::Monotonic),
+///     Err(ClockError::InvalidClock)
+/// );
+/// assert_eq!(ClockError::PermissionDenied.errno(), Errno::EPERM);
+/// assert_eq!(ClockError::OutOfRange.errno(), Errno::EINVAL);
+/// assert_eq!(Errno::from(ClockError::Other(Errno::EFAULT)), Errno::EFAULT);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockError {
+    /// The clock doesn't exist or can't be set (EINVAL).
+    InvalidClock,
+    /// The caller lacks the privilege to set the clock (EPERM).
+    PermissionDenied,
+    /// The time is outside the range the kernel accepts for the clock, e.g.
+    /// negative or not normalized (EINVAL).
+    OutOfRange,
+    /// Any other error.
+    Other(Errno),
+}
+
+impl ClockError {
+    /// Returns the `Errno` this error has been built from.
+    pub fn errno(&self) -> Errno {
+        match *self {
+            Self::InvalidClock => Errno::EINVAL,
+            Self::PermissionDenied => Errno::EPERM,
+            Self::OutOfRange => Errno::EINVAL,
+            Self::Other(errno) => errno,
+        }
+    }
+
+    /// Classifies the error of setting the clock `clockid`: `EINVAL` rejects
+    /// the time when the clock is settable.
+    pub(crate) fn from_settime(clockid: ClockId, errno: Errno) -> Self {
+        match errno {
+            Errno::EINVAL if clockid.is_settable() => Self::OutOfRange,
+            Errno::EINVAL => Self::InvalidClock,
+            Errno::EPERM => Self::PermissionDenied,
+            errno => Self::Other(errno),
+        }
+    }
+}
+
+impl From<ClockError> for Errno {
+    #[inline]
+    fn from(value: ClockError) -> Self {
+        value.errno()
+    }
+}
+
+impl fmt::Display for ClockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidClock => f.write_str("invalid or not settable clock"),
+            Self::PermissionDenied => f.write_str("permission denied"),
+            Self::OutOfRange => f.write_str("time out of range"),
+            Self::Other(errno) => write!(f, "{:?}", errno),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ClockError {}
@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use core::mem::MaybeUninit;
 
 use crate::Errno;
 
@@ -61,6 +61,66 @@ pub enum ClockId {
     ThreadCputimeId = libc::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+impl ClockId {
+    pub(crate) const ALL: &'static [ClockId] = &[
+        Self::Realtime,
+        Self::MonotonicRaw,
+        Self::MonotonicRawApprox,
+        Self::Monotonic,
+        Self::UptimeRaw,
+        Self::UptimeRawApprox,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` if the clock never goes backwards.
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(
+            self,
+            Self::MonotonicRaw
+                | Self::MonotonicRawApprox
+                | Self::Monotonic
+                | Self::UptimeRaw
+                | Self::UptimeRawApprox
+        )
+    }
+
+    /// Returns the name of the `CLOCK_*` constant of this clock.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::MonotonicRaw => "CLOCK_MONOTONIC_RAW",
+            Self::MonotonicRawApprox => "CLOCK_MONOTONIC_RAW_APPROX",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::UptimeRaw => "CLOCK_UPTIME_RAW",
+            Self::UptimeRawApprox => "CLOCK_UPTIME_RAW_APPROX",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "realtime",
+            Self::MonotonicRaw => "monotonicraw",
+            Self::MonotonicRawApprox => "monotonicrawapprox",
+            Self::Monotonic => "monotonic",
+            Self::UptimeRaw => "uptimeraw",
+            Self::UptimeRawApprox => "uptimerawapprox",
+            Self::ProcessCputimeId => "processcputimeid",
+            Self::ThreadCputimeId => "threadcputimeid",
+        }
+    }
+}
+
 #[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
 #[cfg_attr(target_os = "freebsd", repr(i32))]
 #[cfg_attr(target_os = "dragonfly", repr(u64))]
@@ -96,6 +156,80 @@ pub enum ClockId {
     ThreadCputimeId = libc::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+impl ClockId {
+    pub(crate) const ALL: &'static [ClockId] = &[
+        Self::Realtime,
+        Self::RealtimePrecise,
+        Self::RealtimeFast,
+        Self::Monotonic,
+        Self::MonotonicPrecise,
+        Self::MonotonicFast,
+        Self::Uptime,
+        Self::UptimePrecise,
+        Self::UptimeFast,
+        Self::Virtual,
+        Self::Prof,
+        Self::Second,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` if the clock never goes backwards.
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(
+            self,
+            Self::Monotonic
+                | Self::MonotonicPrecise
+                | Self::MonotonicFast
+                | Self::Uptime
+                | Self::UptimePrecise
+                | Self::UptimeFast
+        )
+    }
+
+    /// Returns the name of the `CLOCK_*` constant of this clock.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::RealtimePrecise => "CLOCK_REALTIME_PRECISE",
+            Self::RealtimeFast => "CLOCK_REALTIME_FAST",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::MonotonicPrecise => "CLOCK_MONOTONIC_PRECISE",
+            Self::MonotonicFast => "CLOCK_MONOTONIC_FAST",
+            Self::Uptime => "CLOCK_UPTIME",
+            Self::UptimePrecise => "CLOCK_UPTIME_PRECISE",
+            Self::UptimeFast => "CLOCK_UPTIME_FAST",
+            Self::Virtual => "CLOCK_VIRTUAL",
+            Self::Prof => "CLOCK_PROF",
+            Self::Second => "CLOCK_SECOND",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "realtime",
+            Self::RealtimePrecise => "realtimeprecise",
+            Self::RealtimeFast => "realtimefast",
+            Self::Monotonic => "monotonic",
+            Self::MonotonicPrecise => "monotonicprecise",
+            Self::MonotonicFast => "monotonicfast",
+            Self::Uptime => "uptime",
+            Self::UptimePrecise => "uptimeprecise",
+            Self::UptimeFast => "uptimefast",
+            Self::Virtual => "virtual",
+            Self::Prof => "prof",
+            Self::Second => "second",
+            Self::ProcessCputimeId => "processcputimeid",
+            Self::ThreadCputimeId => "threadcputimeid",
+        }
+    }
+}
+
 #[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
 #[allow(non_upper_case_globals)]
 impl ClockId {
@@ -143,6 +277,48 @@ pub enum ClockId {
     ThreadCputimeId = self::sys::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(target_os = "netbsd")]
+impl ClockId {
+    pub(crate) const ALL: &'static [ClockId] = &[
+        Self::Realtime,
+        Self::Monotonic,
+        Self::Virtual,
+        Self::Prof,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` if the clock never goes backwards.
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(self, Self::Monotonic)
+    }
+
+    /// Returns the name of the `CLOCK_*` constant of this clock.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::Virtual => "CLOCK_VIRTUAL",
+            Self::Prof => "CLOCK_PROF",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "realtime",
+            Self::Monotonic => "monotonic",
+            Self::Virtual => "virtual",
+            Self::Prof => "prof",
+            Self::ProcessCputimeId => "processcputimeid",
+            Self::ThreadCputimeId => "threadcputimeid",
+        }
+    }
+}
+
 #[cfg(target_os = "openbsd")]
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -166,11 +342,56 @@ pub enum ClockId {
     ThreadCputimeId = libc::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(target_os = "openbsd")]
+impl ClockId {
+    pub(crate) const ALL: &'static [ClockId] = &[
+        Self::Realtime,
+        Self::Monotonic,
+        Self::Boottime,
+        Self::Uptime,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` if the clock never goes backwards.
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(self, Self::Monotonic | Self::Boottime | Self::Uptime)
+    }
+
+    /// Returns the name of the `CLOCK_*` constant of this clock.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::Boottime => "CLOCK_BOOTTIME",
+            Self::Uptime => "CLOCK_UPTIME",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "realtime",
+            Self::Monotonic => "monotonic",
+            Self::Boottime => "boottime",
+            Self::Uptime => "uptime",
+            Self::ProcessCputimeId => "processcputimeid",
+            Self::ThreadCputimeId => "threadcputimeid",
+        }
+    }
+}
+
 #[repr(transparent)]
 #[derive(Clone, Copy)]
 pub struct Timespec(libc::timespec);
 
 impl Timespec {
+    /// Creates a new `Timespec` storing `nsecs` verbatim, it's up to the
+    /// caller to keep it below one second. See [`Timespec::from_parts`] for a
+    /// normalizing constructor.
     #[inline(always)]
     pub const fn new(secs: i64, nsecs: u32) -> Self {
         Self(libc::timespec {
@@ -181,6 +402,10 @@ impl Timespec {
 
     #[inline(always)]
     pub fn now(clockid: ClockId) -> Result<Self, Errno> {
+        #[cfg(feature = "mock")]
+        if let Some(res) = super::mock::now(clockid) {
+            return res;
+        }
         let mut buf = MaybeUninit::<libc::timespec>::uninit();
         if unsafe { libc::clock_gettime(clockid as _, buf.as_mut_ptr()) } == -1 {
             Err(Errno::last_os_error())
@@ -189,6 +414,34 @@ impl Timespec {
         }
     }
 
+    /// Reads the current value of the clock `clockid` into `out`, so a
+    /// single buffer can be reused across the readings of a tight loop.
+    #[inline(always)]
+    pub fn now_into(clockid: ClockId, out: &mut Timespec) -> Result<(), Errno> {
+        if unsafe { libc::clock_gettime(clockid as _, &mut out.0) } == -1 {
+            Err(Errno::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the resolution (the tick granularity) of the clock `clockid`.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// assert!(Timespec::resolution(ClockId::Monotonic).unwrap() > Timespec::zero());
+    /// ```
+    #[inline(always)]
+    pub fn resolution(clockid: ClockId) -> Result<Self, Errno> {
+        let mut buf = MaybeUninit::<libc::timespec>::uninit();
+        if unsafe { libc::clock_getres(clockid as _, buf.as_mut_ptr()) } == -1 {
+            Err(Errno::last_os_error())
+        } else {
+            Ok(Self(unsafe { buf.assume_init() }))
+        }
+    }
+
     #[inline(always)]
     pub const fn secs(&self) -> i64 {
         self.0.tv_sec as _
@@ -211,8 +464,19 @@ impl Timespec {
 
     #[inline]
     pub fn set_clock(&self) -> Result<(), Errno> {
-        if unsafe { libc::clock_settime(ClockId::Realtime as _, &self.0 as *const _) } == -1 {
-            Err(Errno::last_os_error())
+        self.set_clock_id(ClockId::Realtime).map_err(Errno::from)
+    }
+
+    /// Sets the clock `clockid` to this time. `EINVAL` is reported both for
+    /// clocks that are not settable and for times a settable clock doesn't
+    /// accept, [`ClockId::is_settable`] tells them apart.
+    #[inline]
+    pub fn set_clock_id(&self, clockid: ClockId) -> Result<(), super::ClockError> {
+        if unsafe { libc::clock_settime(clockid as _, &self.0 as *const _) } == -1 {
+            Err(super::ClockError::from_settime(
+                clockid,
+                Errno::last_os_error(),
+            ))
         } else {
             Ok(())
         }
@@ -1,17 +1,31 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
 use cfg_if::cfg_if;
 use linux_syscalls::{syscall, Errno, Sysno};
 
 cfg_if! {
     if #[cfg(any(target_arch = "x86_64", target_pointer_width = "64"))] {
         #[allow(non_upper_case_globals)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
         const SYS_clock_gettime: Sysno = Sysno::clock_gettime;
         #[allow(non_upper_case_globals)]
         const SYS_clock_settime: Sysno = Sysno::clock_settime;
+        #[allow(non_upper_case_globals)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
+        const SYS_clock_getres: Sysno = Sysno::clock_getres;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_nanosleep: Sysno = Sysno::clock_nanosleep;
     } else {
         #[allow(non_upper_case_globals)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
         const SYS_clock_gettime: Sysno = Sysno::clock_gettime64;
         #[allow(non_upper_case_globals)]
         const SYS_clock_settime: Sysno = Sysno::clock_settime64;
+        #[allow(non_upper_case_globals)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
+        const SYS_clock_getres: Sysno = Sysno::clock_getres_time64;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_nanosleep: Sysno = Sysno::clock_nanosleep_time64;
     }
 }
 
@@ -102,6 +116,248 @@ pub enum ClockId {
     InternationalAtomicTime = linux_raw_sys::general::CLOCK_TAI,
 }
 
+impl ClockId {
+    pub(crate) const ALL: &'static [ClockId] = &[
+        Self::Realtime,
+        Self::Monotonic,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+        Self::MonotonicRaw,
+        Self::RealtimeCoarse,
+        Self::MonotonicCoarse,
+        Self::Boottime,
+        Self::RealtimeAlarm,
+        Self::BoottimeAlarm,
+        Self::InternationalAtomicTime,
+    ];
+
+    /// Returns `true` if the clock never goes backwards.
+    ///
+    /// ```
+    /// use unix_clock::raw::ClockId;
+    ///
+    /// assert!(ClockId::MonotonicRaw.is_monotonic());
+    /// assert!(!ClockId::Realtime.is_monotonic());
+    /// ```
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(
+            self,
+            Self::Monotonic
+                | Self::MonotonicRaw
+                | Self::MonotonicCoarse
+                | Self::Boottime
+                | Self::BoottimeAlarm
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "realtime",
+            Self::Monotonic => "monotonic",
+            Self::ProcessCputimeId => "processcputimeid",
+            Self::ThreadCputimeId => "threadcputimeid",
+            Self::MonotonicRaw => "monotonicraw",
+            Self::RealtimeCoarse => "realtimecoarse",
+            Self::MonotonicCoarse => "monotoniccoarse",
+            Self::Boottime => "boottime",
+            Self::RealtimeAlarm => "realtimealarm",
+            Self::BoottimeAlarm => "boottimealarm",
+            Self::InternationalAtomicTime => "internationalatomictime",
+        }
+    }
+
+    /// Returns the name of the `CLOCK_*` constant of this clock.
+    ///
+    /// ```
+    /// use unix_clock::raw::ClockId;
+    ///
+    /// let names = [
+    ///     "CLOCK_REALTIME",
+    ///     "CLOCK_MONOTONIC",
+    ///     "CLOCK_PROCESS_CPUTIME_ID",
+    ///     "CLOCK_THREAD_CPUTIME_ID",
+    ///     "CLOCK_MONOTONIC_RAW",
+    ///     "CLOCK_REALTIME_COARSE",
+    ///     "CLOCK_MONOTONIC_COARSE",
+    ///     "CLOCK_BOOTTIME",
+    ///     "CLOCK_REALTIME_ALARM",
+    ///     "CLOCK_BOOTTIME_ALARM",
+    ///     "CLOCK_TAI",
+    /// ];
+    /// assert_eq!(ClockId::all().len(), names.len());
+    /// for (id, name) in ClockId::all().iter().zip(names) {
+    ///     assert_eq!(id.as_str(), name);
+    ///     assert_eq!(id.to_string(), name);
+    /// }
+    /// assert_eq!(format!("{:?}", ClockId::Realtime), "Realtime");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+            Self::MonotonicRaw => "CLOCK_MONOTONIC_RAW",
+            Self::RealtimeCoarse => "CLOCK_REALTIME_COARSE",
+            Self::MonotonicCoarse => "CLOCK_MONOTONIC_COARSE",
+            Self::Boottime => "CLOCK_BOOTTIME",
+            Self::RealtimeAlarm => "CLOCK_REALTIME_ALARM",
+            Self::BoottimeAlarm => "CLOCK_BOOTTIME_ALARM",
+            Self::InternationalAtomicTime => "CLOCK_TAI",
+        }
+    }
+}
+
+const CPUCLOCK_PERTHREAD_MASK: i32 = 4;
+const CPUCLOCK_SCHED: i32 = 2;
+const CLOCKFD: i32 = 3;
+
+/// A raw clock identifier (`clockid_t`): either one of the standard clocks
+/// of [`ClockId`] or a dynamic clock the kernel derives from a process, a
+/// thread or a file descriptor (see [`RawClockId::for_pid`],
+/// [`RawClockId::for_tid`] and [`RawClockId::from_fd`]).
+///
+/// [`Timespec::now`] accepts both a `ClockId` and a `RawClockId`.
+///
+/// ```
+/// use unix_clock::raw::{ClockId, RawClockId};
+///
+/// let id = RawClockId::from(ClockId::Monotonic);
+/// assert_eq!(id.as_raw(), ClockId::Monotonic.as_raw());
+/// assert_eq!(id.clock_id(), Some(ClockId::Monotonic));
+/// assert!(!id.is_dynamic());
+/// assert_eq!(RawClockId::for_pid(1).clock_id(), None);
+/// assert!(RawClockId::for_pid(1).is_dynamic());
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RawClockId(i32);
+
+impl RawClockId {
+    /// Returns the CPU-time clock of the process `pid`, the calling process
+    /// if `pid` is zero.
+    ///
+    /// The kernel encodes the id of a CPU-time clock as the bitwise negation
+    /// of the pid in the upper 29 bits, a flag telling whether the pid is
+    /// actually a thread id in bit 2 (CPUCLOCK_PERTHREAD_MASK) and the kind
+    /// of measurement in bits 0-1 (2 for CPUCLOCK_SCHED, the same measure as
+    /// [`ClockId::ProcessCputimeId`] and [`ClockId::ThreadCputimeId`]).
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, RawClockId, Timespec};
+    ///
+    /// let clock = RawClockId::for_pid(std::process::id() as i32);
+    /// let by_pid = Timespec::now(clock).unwrap();
+    /// let own = Timespec::now(ClockId::ProcessCputimeId).unwrap();
+    /// assert!(by_pid > Timespec::zero());
+    /// assert!(own >= by_pid);
+    /// ```
+    #[inline]
+    pub const fn for_pid(pid: i32) -> Self {
+        Self(((!(pid as u32)) << 3) as i32 | CPUCLOCK_SCHED)
+    }
+
+    /// Returns the CPU-time clock of the thread `tid`, the calling thread if
+    /// `tid` is zero. See [`RawClockId::for_pid`] for the encoding.
+    #[inline]
+    pub const fn for_tid(tid: i32) -> Self {
+        Self(((!(tid as u32)) << 3) as i32 | CPUCLOCK_PERTHREAD_MASK | CPUCLOCK_SCHED)
+    }
+
+    /// Returns the dynamic clock backed by the character device opened as
+    /// `fd` (e.g. a PTP hardware clock `/dev/ptpN`). It uses the same
+    /// encoding as [`RawClockId::for_pid`] with CLOCKFD (3) as the low bits.
+    ///
+    /// ```
+    /// use std::os::unix::io::AsRawFd;
+    /// use unix_clock::raw::{RawClockId, Timespec};
+    ///
+    /// if let Ok(ptp) = std::fs::File::open("/dev/ptp0") {
+    ///     assert!(Timespec::now(RawClockId::from_fd(ptp.as_raw_fd())).is_ok());
+    /// }
+    /// ```
+    #[inline]
+    pub const fn from_fd(fd: i32) -> Self {
+        Self(((!(fd as u32)) << 3) as i32 | CLOCKFD)
+    }
+
+    /// Returns the raw `clockid_t` value of this clock.
+    #[inline]
+    pub const fn as_raw(&self) -> i32 {
+        self.0
+    }
+
+    /// Returns `true` if this is a dynamic clock derived from a pid, a tid or
+    /// a file descriptor.
+    #[inline]
+    pub const fn is_dynamic(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the standard clock this id stands for, `None` for the dynamic
+    /// ones.
+    #[inline]
+    pub const fn clock_id(&self) -> Option<ClockId> {
+        use linux_raw_sys::general::{
+            CLOCK_BOOTTIME, CLOCK_BOOTTIME_ALARM, CLOCK_MONOTONIC, CLOCK_MONOTONIC_COARSE,
+            CLOCK_MONOTONIC_RAW, CLOCK_PROCESS_CPUTIME_ID, CLOCK_REALTIME, CLOCK_REALTIME_ALARM,
+            CLOCK_REALTIME_COARSE, CLOCK_TAI, CLOCK_THREAD_CPUTIME_ID,
+        };
+
+        Some(match self.0 as u32 {
+            CLOCK_REALTIME => ClockId::Realtime,
+            CLOCK_MONOTONIC => ClockId::Monotonic,
+            CLOCK_PROCESS_CPUTIME_ID => ClockId::ProcessCputimeId,
+            CLOCK_THREAD_CPUTIME_ID => ClockId::ThreadCputimeId,
+            CLOCK_MONOTONIC_RAW => ClockId::MonotonicRaw,
+            CLOCK_REALTIME_COARSE => ClockId::RealtimeCoarse,
+            CLOCK_MONOTONIC_COARSE => ClockId::MonotonicCoarse,
+            CLOCK_BOOTTIME => ClockId::Boottime,
+            CLOCK_REALTIME_ALARM => ClockId::RealtimeAlarm,
+            CLOCK_BOOTTIME_ALARM => ClockId::BoottimeAlarm,
+            CLOCK_TAI => ClockId::InternationalAtomicTime,
+            _ => return None,
+        })
+    }
+}
+
+impl From<ClockId> for RawClockId {
+    #[inline]
+    fn from(clockid: ClockId) -> Self {
+        Self(clockid.as_raw())
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SleepFlags {
+    /// The requested time is an interval relative to the current value of
+    /// the clock.
+    Relative = 0,
+
+    /// The requested time is an absolute value of the clock (TIMER_ABSTIME).
+    /// If it is less than or equal to the current value of the clock the
+    /// sleep returns immediately.
+    Absolute = linux_raw_sys::general::TIMER_ABSTIME,
+}
+
+/// A time value with the layout of the kernel `__kernel_timespec`: a 64-bit
+/// `tv_sec` followed by a 64-bit `tv_nsec` whose upper half is always zero.
+///
+/// ```
+/// use unix_clock::raw::Timespec;
+///
+/// let t = Timespec::new(0x0102_0304_0506_0708, 0x0a0b_0c0d);
+/// let bytes: [u8; 16] = unsafe { core::mem::transmute(t) };
+/// assert_eq!(bytes[..8], 0x0102_0304_0506_0708i64.to_ne_bytes());
+/// assert_eq!(bytes[8..], 0x0a0b_0c0di64.to_ne_bytes());
+/// #[cfg(target_endian = "little")]
+/// assert_eq!(bytes[8..], [0x0d, 0x0c, 0x0b, 0x0a, 0, 0, 0, 0]);
+/// #[cfg(target_endian = "big")]
+/// assert_eq!(bytes[8..], [0, 0, 0, 0, 0x0a, 0x0b, 0x0c, 0x0d]);
+/// ```
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Timespec {
@@ -113,27 +369,276 @@ pub struct Timespec {
     __padding: i32,
 }
 
+// `i64` is only 4-byte aligned on some 32-bit targets (e.g. x86), like the
+// kernel's `long long`, so only the size is pinned.
+const _: () = assert!(core::mem::size_of::<Timespec>() == 16);
+
 cfg_if! {
-    if #[cfg(any(
-        target_arch = "x86_64", // supporting x32 too
-        target_arch = "powerpc",
+    // The 32-bit architectures predating the time64 syscalls, the newer ones
+    // (e.g. riscv32 and loongarch32) never had the legacy ones.
+    if #[cfg(not(any(
+        target_arch = "x86",
         target_arch = "arm",
         target_arch = "mips",
-        target_arch = "x86",
-        target_pointer_width = "64"
+        target_arch = "powerpc",
+        target_arch = "sparc",
+        target_arch = "m68k"
+    )))] {
+        #[inline(always)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
+        unsafe fn sys_clock_gettime(clockid: RawClockId, buf: *mut Timespec) -> Result<(), Errno> {
+            syscall!(SYS_clock_gettime, clockid.as_raw(), buf).map(|_| ())
+        }
+
+        #[inline(always)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
+        unsafe fn sys_clock_getres(clockid: RawClockId, buf: *mut Timespec) -> Result<(), Errno> {
+            syscall!(SYS_clock_getres, clockid.as_raw(), buf).map(|_| ())
+        }
+    } else {
+        // The 32-bit `timespec` of the legacy syscalls.
+        #[repr(C)]
+        struct Timespec32 {
+            tv_sec: i32,
+            tv_nsec: i32,
+        }
+
+        // The time64 syscalls were added in Linux 5.1, older kernels only
+        // have the legacy ones, which break in 2038.
+        #[inline(always)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
+        unsafe fn time64_or_legacy(
+            time64: impl FnOnce(*mut Timespec) -> Result<usize, Errno>,
+            legacy: impl FnOnce(*mut Timespec32) -> Result<usize, Errno>,
+            buf: *mut Timespec,
+        ) -> Result<(), Errno> {
+            match time64(buf) {
+                Err(Errno::ENOSYS) => {
+                    let mut old = Timespec32 { tv_sec: 0, tv_nsec: 0 };
+                    legacy(&mut old)?;
+                    *buf = Timespec::new(old.tv_sec as i64, old.tv_nsec as u32);
+                    Ok(())
+                }
+                res => res.map(|_| ()),
+            }
+        }
+
+        #[inline(always)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
+        unsafe fn sys_clock_gettime(clockid: RawClockId, buf: *mut Timespec) -> Result<(), Errno> {
+            time64_or_legacy(
+                |buf| syscall!(SYS_clock_gettime, clockid.as_raw(), buf),
+                |old| syscall!(Sysno::clock_gettime, clockid.as_raw(), old),
+                buf,
+            )
+        }
+
+        #[inline(always)]
+        #[cfg_attr(feature = "libc-backend", allow(dead_code))]
+        unsafe fn sys_clock_getres(clockid: RawClockId, buf: *mut Timespec) -> Result<(), Errno> {
+            time64_or_legacy(
+                |buf| syscall!(SYS_clock_getres, clockid.as_raw(), buf),
+                |old| syscall!(Sysno::clock_getres, clockid.as_raw(), old),
+                buf,
+            )
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            unsafe fn run(
+                time64: impl FnOnce(*mut Timespec) -> Result<usize, Errno>,
+                legacy: impl FnOnce(*mut Timespec32) -> Result<usize, Errno>,
+            ) -> Result<Timespec, Errno> {
+                let mut buf = Timespec::new(-1, 0);
+                time64_or_legacy(time64, legacy, &mut buf).map(|_| buf)
+            }
+
+            #[test]
+            fn time64_success_skips_legacy() {
+                let t = unsafe {
+                    run(
+                        |buf| {
+                            *buf = Timespec::new(1 << 40, 1);
+                            Ok(0)
+                        },
+                        |_| unreachable!(),
+                    )
+                };
+                assert_eq!(t, Ok(Timespec::new(1 << 40, 1)));
+            }
+
+            #[test]
+            fn time64_error_is_returned() {
+                let t = unsafe { run(|_| Err(Errno::EINVAL), |_| unreachable!()) };
+                assert_eq!(t, Err(Errno::EINVAL));
+            }
+
+            #[test]
+            fn enosys_falls_back_to_legacy() {
+                let t = unsafe {
+                    run(
+                        |_| Err(Errno::ENOSYS),
+                        |old| {
+                            *old = Timespec32 {
+                                tv_sec: -2,
+                                tv_nsec: 999_999_999,
+                            };
+                            Ok(0)
+                        },
+                    )
+                };
+                assert_eq!(t, Ok(Timespec::new(-2, 999_999_999)));
+            }
+
+            #[test]
+            fn enosys_legacy_error_is_returned() {
+                let t = unsafe { run(|_| Err(Errno::ENOSYS), |_| Err(Errno::EINVAL)) };
+                assert_eq!(t, Err(Errno::EINVAL));
+            }
+
+            #[test]
+            fn enosys_on_real_clocks() {
+                let mut buf = Timespec::zero();
+                unsafe {
+                    time64_or_legacy(
+                        |_| Err(Errno::ENOSYS),
+                        |old| syscall!(Sysno::clock_gettime, ClockId::Monotonic.as_raw(), old),
+                        &mut buf,
+                    )
+                }
+                .unwrap();
+                assert!(buf > Timespec::zero() && buf.is_normalized());
+            }
+        }
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "libc-backend")] {
+        mod get_impl {
+            use core::mem::MaybeUninit;
+
+            use linux_syscalls::Errno;
+
+            #[inline(always)]
+            unsafe fn check(ret: i32, buf: MaybeUninit<libc::timespec>) -> Result<super::Timespec, Errno> {
+                if ret == 0 {
+                    Ok(buf.assume_init().into())
+                } else {
+                    Err(Errno::new(*libc::__errno_location()))
+                }
+            }
+
+            #[inline(always)]
+            pub fn clock_gettime(clockid: super::RawClockId) -> Result<super::Timespec, Errno> {
+                unsafe {
+                    let mut buf = MaybeUninit::<libc::timespec>::uninit();
+                    check(libc::clock_gettime(clockid.as_raw(), buf.as_mut_ptr()), buf)
+                }
+            }
+
+            cfg_if::cfg_if! {
+                if #[cfg(target_pointer_width = "64")] {
+                    // `libc::timespec` has the layout of the kernel one here,
+                    // libc writes straight into `out`.
+                    const _: () = assert!(
+                        core::mem::size_of::<libc::timespec>() == core::mem::size_of::<super::Timespec>()
+                    );
+
+                    #[inline(always)]
+                    pub fn clock_gettime_into(clockid: super::RawClockId, out: &mut super::Timespec) -> Result<(), Errno> {
+                        unsafe {
+                            let buf = out as *mut super::Timespec as *mut libc::timespec;
+                            if libc::clock_gettime(clockid.as_raw(), buf) == 0 {
+                                Ok(())
+                            } else {
+                                Err(Errno::new(*libc::__errno_location()))
+                            }
+                        }
+                    }
+                } else {
+                    // `libc::timespec` may have a 32-bit `tv_sec`, go through
+                    // a copy.
+                    #[inline(always)]
+                    pub fn clock_gettime_into(clockid: super::RawClockId, out: &mut super::Timespec) -> Result<(), Errno> {
+                        *out = clock_gettime(clockid)?;
+                        Ok(())
+                    }
+                }
+            }
+
+            #[inline(always)]
+            pub fn clock_gettime_with_source(
+                clockid: super::RawClockId,
+            ) -> Result<(super::Timespec, super::ClockSource), Errno> {
+                clock_gettime(clockid).map(|t| (t, super::ClockSource::Libc))
+            }
+
+            #[inline(always)]
+            pub fn clock_getres(clockid: super::RawClockId) -> Result<super::Timespec, Errno> {
+                unsafe {
+                    let mut buf = MaybeUninit::<libc::timespec>::uninit();
+                    check(libc::clock_getres(clockid.as_raw(), buf.as_mut_ptr()), buf)
+                }
+            }
+
+            #[inline(always)]
+            pub fn vdso_available() -> bool {
+                false
+            }
+
+            #[inline(always)]
+            pub fn set_vdso_enabled(_enabled: bool) {}
+        }
+    } else if #[cfg(all(
+        not(feature = "no-vdso"),
+        any(
+            target_arch = "x86_64", // supporting x32 too
+            target_arch = "powerpc",
+            target_arch = "arm",
+            target_arch = "mips",
+            target_arch = "x86",
+            target_pointer_width = "64"
+        )
     ))] {
         mod get_impl {
             use core::{
                 mem::MaybeUninit,
-                sync::atomic::{AtomicPtr, Ordering},
+                sync::atomic::{AtomicBool, AtomicPtr, Ordering},
             };
 
-            use linux_syscalls::{syscall, Errno};
+            use linux_syscalls::Errno;
 
             const UNINIT: *mut core::ffi::c_void = core::ptr::null_mut();
             const INIT_NULL: *mut core::ffi::c_void = 1 as _;
-            static mut CLOCK_GETTIME_VSYSCALL: AtomicPtr<core::ffi::c_void> =
-                AtomicPtr::new(core::ptr::null_mut());
+            static CLOCK_GETTIME_VSYSCALL: AtomicPtr<core::ffi::c_void> = AtomicPtr::new(UNINIT);
+            static CLOCK_GETRES_VSYSCALL: AtomicPtr<core::ffi::c_void> = AtomicPtr::new(UNINIT);
+            static VDSO_ENABLED: AtomicBool = AtomicBool::new(true);
+
+            type Vsyscall = extern "C" fn(super::RawClockId, *mut super::Timespec) -> usize;
+
+            // The clocks handled by the vDSO itself, it falls back to the
+            // syscall for every other one (CPU-time, alarm and dynamic clocks)
+            // so it's pointless to go through it.
+            #[inline(always)]
+            fn vdso_serves(clockid: super::RawClockId) -> bool {
+                use super::ClockId;
+
+                matches!(
+                    clockid.clock_id(),
+                    Some(
+                        ClockId::Realtime
+                            | ClockId::Monotonic
+                            | ClockId::MonotonicRaw
+                            | ClockId::RealtimeCoarse
+                            | ClockId::MonotonicCoarse
+                            | ClockId::Boottime
+                            | ClockId::InternationalAtomicTime
+                    )
+                )
+            }
 
             cfg_if::cfg_if! {
                 if #[cfg(any(
@@ -146,66 +651,292 @@ cfg_if! {
                     fn vdso_clock_gettime(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
                         vdso.clock_gettime64()
                     }
+
+                    // The 32-bit vDSO only exposes a `clock_getres` working on the
+                    // legacy 32-bit timespec, always go through the syscall.
+                    #[inline(always)]
+                    fn vdso_clock_getres(_vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
+                        core::ptr::null()
+                    }
+                } else if #[cfg(target_arch = "x86_64")] {
+                    #[inline(always)]
+                    fn vdso_clock_gettime(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
+                        vdso.clock_gettime()
+                    }
+
+                    // The x86_64 vDSO doesn't export `clock_getres`.
+                    #[inline(always)]
+                    fn vdso_clock_getres(_vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
+                        core::ptr::null()
+                    }
                 } else {
                     #[inline(always)]
                     fn vdso_clock_gettime(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
                         vdso.clock_gettime()
                     }
+
+                    #[inline(always)]
+                    fn vdso_clock_getres(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
+                        vdso.clock_getres()
+                    }
                 }
             }
 
             #[inline(always)]
-            fn clock_gettime_vsyscall(
-            ) -> Option<extern "C" fn(super::ClockId, *mut super::Timespec) -> usize> {
+            unsafe fn load_vsyscall(
+                cache: &AtomicPtr<core::ffi::c_void>,
+                resolve: fn(&linux_syscalls::env::Vdso) -> *const core::ffi::c_void,
+            ) -> Option<Vsyscall> {
+                // Racing initializations resolve the very same pointer, the
+                // Release/Acquire pair only has to publish it to the readers.
+                match cache.load(Ordering::Acquire) {
+                    UNINIT => {
+                        let ptr = resolve(linux_syscalls::env::unchecked_vdso()) as *mut core::ffi::c_void;
+                        if ptr.is_null() {
+                            cache.store(INIT_NULL, Ordering::Release);
+                            None
+                        } else {
+                            cache.store(ptr, Ordering::Release);
+                            Some(core::mem::transmute::<*mut core::ffi::c_void, Vsyscall>(ptr))
+                        }
+                    }
+                    INIT_NULL => None,
+                    ptr => Some(core::mem::transmute::<*mut core::ffi::c_void, Vsyscall>(ptr)),
+                }
+            }
+
+            #[inline(always)]
+            fn clock_gettime_vsyscall() -> Option<Vsyscall> {
+                if !VDSO_ENABLED.load(Ordering::Relaxed) {
+                    return None;
+                }
+                unsafe { load_vsyscall(&CLOCK_GETTIME_VSYSCALL, vdso_clock_gettime) }
+            }
+
+            #[inline(always)]
+            fn clock_getres_vsyscall() -> Option<Vsyscall> {
+                if !VDSO_ENABLED.load(Ordering::Relaxed) {
+                    return None;
+                }
+                unsafe { load_vsyscall(&CLOCK_GETRES_VSYSCALL, vdso_clock_getres) }
+            }
+
+            #[inline(always)]
+            pub fn vdso_available() -> bool {
+                clock_gettime_vsyscall().is_some()
+            }
+
+            #[inline(always)]
+            pub fn set_vdso_enabled(enabled: bool) {
+                VDSO_ENABLED.store(enabled, Ordering::Relaxed);
+            }
+
+            #[inline(always)]
+            pub fn clock_gettime(clockid: super::RawClockId) -> Result<super::Timespec, Errno> {
+                clock_gettime_with_source(clockid).map(|(t, _)| t)
+            }
+
+            #[inline(always)]
+            pub fn clock_gettime_with_source(
+                clockid: super::RawClockId,
+            ) -> Result<(super::Timespec, super::ClockSource), Errno> {
                 unsafe {
-                    match CLOCK_GETTIME_VSYSCALL.load(Ordering::Relaxed) {
-                        UNINIT => {
-                            let ptr =
-                                vdso_clock_gettime(linux_syscalls::env::unchecked_vdso()) as *mut core::ffi::c_void;
-                            if ptr.is_null() {
-                                CLOCK_GETTIME_VSYSCALL.store(INIT_NULL, Ordering::Relaxed);
-                                None
-                            } else {
-                                CLOCK_GETTIME_VSYSCALL.store(ptr, Ordering::Relaxed);
-                                Some(core::mem::transmute(ptr))
-                            }
+                    let mut buf = MaybeUninit::<super::Timespec>::uninit();
+                    (*buf.as_mut_ptr()).__padding = 0;
+                    if let Some(inner) = clock_gettime_vsyscall().filter(|_| vdso_serves(clockid)) {
+                        match Errno::from_ret(inner(clockid, buf.as_mut_ptr())) {
+                            Err(Errno::ENOSYS) => (),
+                            other => return other.map(|_| (buf.assume_init(), super::ClockSource::Vdso)),
+                        }
+                    }
+
+                    super::sys_clock_gettime(clockid, buf.as_mut_ptr())
+                        .map(|_| (buf.assume_init(), super::ClockSource::Syscall))
+                }
+            }
+
+            pub fn clock_gettime_into(clockid: super::RawClockId, out: &mut super::Timespec) -> Result<(), Errno> {
+                out.__padding = 0;
+                let out = out as *mut super::Timespec;
+                unsafe {
+                    if let Some(inner) = clock_gettime_vsyscall().filter(|_| vdso_serves(clockid)) {
+                        match Errno::from_ret(inner(clockid, out)) {
+                            Err(Errno::ENOSYS) => (),
+                            other => return other.map(|_| ()),
                         }
-                        INIT_NULL => None,
-                        ptr => Some(core::mem::transmute(ptr)),
                     }
+
+                    super::sys_clock_gettime(clockid, out)
                 }
             }
 
-            pub fn clock_gettime(clockid: super::ClockId) -> Result<super::Timespec, Errno> {
+            pub fn clock_getres(clockid: super::RawClockId) -> Result<super::Timespec, Errno> {
                 unsafe {
                     let mut buf = MaybeUninit::<super::Timespec>::uninit();
                     (*buf.as_mut_ptr()).__padding = 0;
-                    if let Some(inner) = clock_gettime_vsyscall() {
+                    if let Some(inner) = clock_getres_vsyscall().filter(|_| vdso_serves(clockid)) {
                         match Errno::from_ret(inner(clockid, buf.as_mut_ptr())) {
                             Err(Errno::ENOSYS) => (),
                             other => return other.map(|_| buf.assume_init()),
                         }
                     }
 
-                    syscall!(super::SYS_clock_gettime, clockid, buf.as_mut_ptr()).map(|_| buf.assume_init())
+                    super::sys_clock_getres(clockid, buf.as_mut_ptr()).map(|_| buf.assume_init())
                 }
             }
         }
     } else {
+        // Every other target (or the `no-vdso` feature) goes straight to the
+        // syscall.
         mod get_impl {
+            use core::mem::MaybeUninit;
+
+            use linux_syscalls::Errno;
+
             #[inline(always)]
-            pub fn clock_gettime(clockid: super::ClockId) -> Result<super::Timespec, Errno> {
+            pub fn clock_gettime(clockid: super::RawClockId) -> Result<super::Timespec, Errno> {
                 unsafe {
                     let mut buf = MaybeUninit::<super::Timespec>::uninit();
                     (*buf.as_mut_ptr()).__padding = 0;
-                    syscall!(super::SYS_clock_gettime, clockid, buf.as_mut_ptr()).map(|_| buf.assume_init())
+                    super::sys_clock_gettime(clockid, buf.as_mut_ptr()).map(|_| buf.assume_init())
                 }
             }
+
+            #[inline(always)]
+            pub fn clock_gettime_into(clockid: super::RawClockId, out: &mut super::Timespec) -> Result<(), Errno> {
+                out.__padding = 0;
+                unsafe { super::sys_clock_gettime(clockid, out) }
+            }
+
+            #[inline(always)]
+            pub fn clock_gettime_with_source(
+                clockid: super::RawClockId,
+            ) -> Result<(super::Timespec, super::ClockSource), Errno> {
+                clock_gettime(clockid).map(|t| (t, super::ClockSource::Syscall))
+            }
+
+            #[inline(always)]
+            pub fn clock_getres(clockid: super::RawClockId) -> Result<super::Timespec, Errno> {
+                unsafe {
+                    let mut buf = MaybeUninit::<super::Timespec>::uninit();
+                    (*buf.as_mut_ptr()).__padding = 0;
+                    super::sys_clock_getres(clockid, buf.as_mut_ptr()).map(|_| buf.assume_init())
+                }
+            }
+
+            #[inline(always)]
+            pub fn vdso_available() -> bool {
+                false
+            }
+
+            #[inline(always)]
+            pub fn set_vdso_enabled(_enabled: bool) {}
         }
     }
 }
 
+/// The path that served a clock reading, see [`Timespec::now_with_source`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClockSource {
+    /// The vDSO `clock_gettime`, without entering the kernel.
+    Vdso,
+    /// The `clock_gettime` syscall.
+    Syscall,
+    /// The libc `clock_gettime`, with the `libc-backend` feature.
+    Libc,
+}
+
+/// Returns `true` if [`Timespec::now`] is served by the vDSO `clock_gettime`
+/// for the clocks it supports, `false` if every reading goes through the
+/// syscall (e.g. the vDSO is missing or disabled with
+/// [`set_vdso_enabled`], or the `libc-backend` feature is enabled, leaving
+/// the choice to libc).
+///
+/// ```
+/// let _ = unix_clock::raw::vdso_available();
+/// ```
+#[inline]
+pub fn vdso_available() -> bool {
+    get_impl::vdso_available()
+}
+
+/// Enables or disables the vDSO fast path at runtime, e.g. in sandboxes
+/// where the vDSO is unmapped or unusable. While disabled every reading goes
+/// through the syscall. This has no effect when the vDSO path is compiled out
+/// (with the `no-vdso` or `libc-backend` features, or on targets without it).
+///
+/// ```
+/// use unix_clock::raw::{set_vdso_enabled, vdso_available, ClockId, Timespec};
+///
+/// set_vdso_enabled(false);
+/// assert!(!vdso_available());
+/// assert!(Timespec::now(ClockId::Monotonic).unwrap() > Timespec::zero());
+/// set_vdso_enabled(true);
+/// # #[cfg(feature = "no-vdso")]
+/// assert!(!vdso_available());
+/// ```
+#[inline]
+pub fn set_vdso_enabled(enabled: bool) {
+    get_impl::set_vdso_enabled(enabled)
+}
+
+/// Whether to read the coarse variant of a clock, see
+/// [`Timespec::now_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrecisionMode {
+    /// The coarse variant ([`ClockId::MonotonicCoarse`] or
+    /// [`ClockId::RealtimeCoarse`]), cheaper but only as precise as a tick.
+    Coarse,
+    /// The clock itself.
+    Fine,
+}
+
+impl PrecisionMode {
+    /// Returns the clock read for `base` in this mode, `base` itself for
+    /// the clocks without a coarse variant.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, PrecisionMode};
+    ///
+    /// assert_eq!(PrecisionMode::Coarse.clock_id(ClockId::Monotonic), ClockId::MonotonicCoarse);
+    /// assert_eq!(PrecisionMode::Coarse.clock_id(ClockId::Realtime), ClockId::RealtimeCoarse);
+    /// assert_eq!(PrecisionMode::Coarse.clock_id(ClockId::Boottime), ClockId::Boottime);
+    /// assert_eq!(PrecisionMode::Fine.clock_id(ClockId::Monotonic), ClockId::Monotonic);
+    /// assert_eq!(PrecisionMode::Fine.clock_id(ClockId::Realtime), ClockId::Realtime);
+    /// assert_eq!(PrecisionMode::Fine.clock_id(ClockId::Boottime), ClockId::Boottime);
+    /// ```
+    #[inline]
+    pub const fn clock_id(self, base: ClockId) -> ClockId {
+        match (base, self) {
+            (ClockId::Monotonic, Self::Coarse) => ClockId::MonotonicCoarse,
+            (ClockId::Realtime, Self::Coarse) => ClockId::RealtimeCoarse,
+            _ => base,
+        }
+    }
+}
+
+static MONOTONIC_COARSE_UNSUPPORTED: AtomicBool = AtomicBool::new(false);
+static REALTIME_COARSE_UNSUPPORTED: AtomicBool = AtomicBool::new(false);
+
+#[inline(always)]
+fn now_or_fallback(
+    unsupported: &AtomicBool,
+    clockid: ClockId,
+    fallback: ClockId,
+) -> Result<Timespec, Errno> {
+    if !unsupported.load(Ordering::Relaxed) {
+        match Timespec::now(clockid) {
+            Err(Errno::EINVAL) => unsupported.store(true, Ordering::Relaxed),
+            res => return res,
+        }
+    }
+    Timespec::now(fallback)
+}
+
 impl Timespec {
+    /// Creates a new `Timespec` storing `nsecs` verbatim, it's up to the
+    /// caller to keep it below one second. See [`Timespec::from_parts`] for a
+    /// normalizing constructor.
     #[inline(always)]
     pub const fn new(secs: i64, nsecs: u32) -> Self {
         Self {
@@ -215,11 +946,325 @@ impl Timespec {
         }
     }
 
+    /// Reads the current value of the clock `clockid`, either a [`ClockId`]
+    /// or a dynamic [`RawClockId`]. The dynamic clocks always go through the
+    /// syscall and are never mocked.
+    ///
+    /// ```
+    /// use unix_clock::{raw::{ClockId, Timespec}, Errno};
+    ///
+    /// // Alarm clocks are never served by the vDSO, this one may only fail
+    /// // when the machine lacks an RTC able to wake it up.
+    /// match Timespec::now(ClockId::BoottimeAlarm) {
+    ///     Ok(_) | Err(Errno::EINVAL) => (),
+    ///     Err(err) => panic!("{:?}", err),
+    /// }
+    /// ```
     #[inline(always)]
-    pub fn now(clockid: ClockId) -> Result<Self, Errno> {
+    pub fn now(clockid: impl Into<RawClockId>) -> Result<Self, Errno> {
+        let clockid = clockid.into();
+        #[cfg(feature = "mock")]
+        if let Some(res) = clockid.clock_id().and_then(super::mock::now) {
+            return res;
+        }
         get_impl::clock_gettime(clockid)
     }
 
+    /// Like [`Timespec::now`] but retries the reading when interrupted by a
+    /// signal handler. Reading a clock only blocks when it has to go through
+    /// a device (e.g. a PTP clock, see [`RawClockId::from_fd`]).
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let t = Timespec::now_uninterrupted(ClockId::Monotonic).unwrap();
+    /// assert!(t <= Timespec::now(ClockId::Monotonic).unwrap());
+    /// ```
+    #[inline]
+    pub fn now_uninterrupted(clockid: impl Into<RawClockId>) -> Result<Self, Errno> {
+        let clockid = clockid.into();
+        super::retry_on_eintr(|| Self::now(clockid))
+    }
+
+    /// Like [`Timespec::now`], also returning the path that served the
+    /// reading.
+    ///
+    /// ```
+    /// use unix_clock::raw::{vdso_available, ClockId, ClockSource, Timespec};
+    ///
+    /// let (_, source) = Timespec::now_with_source(ClockId::Monotonic).unwrap();
+    /// if vdso_available() {
+    ///     assert_eq!(source, ClockSource::Vdso);
+    /// }
+    /// let (_, source) = Timespec::now_with_source(ClockId::ProcessCputimeId).unwrap();
+    /// assert_ne!(source, ClockSource::Vdso);
+    /// ```
+    #[inline(always)]
+    pub fn now_with_source(clockid: ClockId) -> Result<(Self, ClockSource), Errno> {
+        get_impl::clock_gettime_with_source(clockid.into())
+    }
+
+    /// Reads [`ClockId::MonotonicRaw`] twice in a row, returning the first
+    /// reading and the delta between the two as an estimate of the cost of a
+    /// read, to tell whether the clock is cheap enough for tight
+    /// measurements.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let (t, overhead) = Timespec::now_raw_overhead().unwrap();
+    /// assert!(t > Timespec::zero());
+    /// assert!(overhead < Duration::from_millis(100));
+    /// ```
+    pub fn now_raw_overhead() -> Result<(Self, core::time::Duration), Errno> {
+        let (first, second) = Self::now_pair(ClockId::MonotonicRaw, ClockId::MonotonicRaw)?;
+        Ok((first, second.sub_timespec(&first).unwrap_or_default()))
+    }
+
+    /// Reads the current value of the clock `clockid` into `out`, so a
+    /// single buffer can be reused across the readings of a tight loop.
+    ///
+    /// With the `libc-backend` feature on 32-bit targets the reading goes
+    /// through a temporary `libc::timespec`, copied into `out` afterwards.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let mut t = Timespec::zero();
+    /// Timespec::now_into(ClockId::Monotonic, &mut t).unwrap();
+    /// let first = t;
+    /// Timespec::now_into(ClockId::Monotonic, &mut t).unwrap();
+    /// assert!(t >= first && first > Timespec::zero());
+    /// ```
+    #[inline(always)]
+    pub fn now_into(clockid: ClockId, out: &mut Timespec) -> Result<(), Errno> {
+        get_impl::clock_gettime_into(clockid.into(), out)
+    }
+
+    /// Reads [`ClockId::MonotonicCoarse`], falling back to
+    /// [`ClockId::Monotonic`] if it isn't supported. The fallback is
+    /// remembered so the coarse clock is probed only once.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let fine = Timespec::now(ClockId::Monotonic).unwrap();
+    /// let coarse = Timespec::now_coarse_monotonic().unwrap();
+    /// assert!(coarse.abs_diff(&fine) < std::time::Duration::from_secs(1));
+    /// ```
+    #[inline]
+    pub fn now_coarse_monotonic() -> Result<Self, Errno> {
+        now_or_fallback(
+            &MONOTONIC_COARSE_UNSUPPORTED,
+            ClockId::MonotonicCoarse,
+            ClockId::Monotonic,
+        )
+    }
+
+    /// Reads [`ClockId::RealtimeCoarse`], falling back to
+    /// [`ClockId::Realtime`] if it isn't supported. The fallback is
+    /// remembered so the coarse clock is probed only once.
+    #[inline]
+    pub fn now_coarse_realtime() -> Result<Self, Errno> {
+        now_or_fallback(
+            &REALTIME_COARSE_UNSUPPORTED,
+            ClockId::RealtimeCoarse,
+            ClockId::Realtime,
+        )
+    }
+
+    /// Reads the clock `base` with the precision `mode`, see
+    /// [`PrecisionMode::clock_id`]. Coarse reads fall back to `base` if the
+    /// coarse clock isn't supported, like [`Timespec::now_coarse_monotonic`].
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{ClockId, PrecisionMode, Timespec};
+    ///
+    /// let coarse = Timespec::now_mode(ClockId::Monotonic, PrecisionMode::Coarse).unwrap();
+    /// let fine = Timespec::now_mode(ClockId::Monotonic, PrecisionMode::Fine).unwrap();
+    /// assert!(coarse.abs_diff(&fine) < Duration::from_secs(1));
+    /// ```
+    #[inline]
+    pub fn now_mode(base: ClockId, mode: PrecisionMode) -> Result<Self, Errno> {
+        match (base, mode) {
+            (ClockId::Monotonic, PrecisionMode::Coarse) => Self::now_coarse_monotonic(),
+            (ClockId::Realtime, PrecisionMode::Coarse) => Self::now_coarse_realtime(),
+            _ => Self::now(base),
+        }
+    }
+
+    /// Returns the resolution (the tick granularity) of the clock `clockid`.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let fine = Timespec::resolution(ClockId::Realtime).unwrap();
+    /// let coarse = Timespec::resolution(ClockId::RealtimeCoarse).unwrap();
+    /// assert!(Timespec::resolution(ClockId::Monotonic).unwrap() > Timespec::zero());
+    /// assert!(coarse >= fine);
+    /// ```
+    ///
+    /// With the `libc-backend` feature the clocks are read through libc,
+    /// giving the same results as the raw syscalls:
+    ///
+    /// ```
+    /// # #[cfg(feature = "libc-backend")] {
+    /// use linux_syscalls::{syscall, Sysno};
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// // The legacy `clock_getres` of 32-bit targets writes a 32-bit timespec.
+    /// #[cfg(any(target_arch = "x86_64", target_pointer_width = "64"))]
+    /// let sysno = Sysno::clock_getres;
+    /// #[cfg(not(any(target_arch = "x86_64", target_pointer_width = "64")))]
+    /// let sysno = Sysno::clock_getres_time64;
+    ///
+    /// let mut raw = Timespec::zero();
+    /// unsafe { syscall!(sysno, ClockId::Monotonic.as_raw(), &mut raw as *mut Timespec) }.unwrap();
+    /// assert_eq!(Timespec::resolution(ClockId::Monotonic).unwrap(), raw);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn resolution(clockid: ClockId) -> Result<Self, Errno> {
+        get_impl::clock_getres(clockid.into())
+    }
+
+    /// Clamps `self` to the range accepted by [`Timespec::sleep`]: the kernel
+    /// fails with `EINVAL` on negative or denormalized times, and caps the
+    /// others to `KTIME_MAX`, `i64::MAX` nanoseconds (about 292 years). A
+    /// negative deadline becomes zero, which is always past, and anything
+    /// beyond `KTIME_MAX` becomes `KTIME_MAX`.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let max = Timespec::from_nanos(i64::MAX as i128).unwrap();
+    /// assert_eq!(Timespec::MAX.clamp_for_sleep(), max);
+    /// assert_eq!(Timespec::new(1, 3_000_000_000).clamp_for_sleep(), Timespec::new(4, 0));
+    ///
+    /// let past = Timespec::new(-1_000_000, 0);
+    /// assert!(Timespec::sleep_until(ClockId::Monotonic, &past).is_err());
+    /// assert_eq!(past.clamp_for_sleep(), Timespec::zero());
+    /// Timespec::sleep_until(ClockId::Monotonic, &past.clamp_for_sleep()).unwrap();
+    /// ```
+    pub const fn clamp_for_sleep(&self) -> Timespec {
+        let nanos = self.as_nanos();
+        if nanos <= 0 {
+            Self::zero()
+        } else {
+            let nanos = if nanos > i64::MAX as i128 {
+                i64::MAX
+            } else {
+                nanos as i64
+            };
+            let nsec_per_sec = super::NSEC_PER_SEC as i64;
+            Self::new(nanos / nsec_per_sec, (nanos % nsec_per_sec) as u32)
+        }
+    }
+
+    /// Suspends the calling thread until `request` is reached against the
+    /// clock `clockid`, interpreting it according to `flags`.
+    ///
+    /// Returns `Ok(None)` when the whole sleep has been performed. If the
+    /// sleep is interrupted by a signal handler it returns `Ok(Some(t))`,
+    /// where `t` is the time to pass again with the same `flags` to resume
+    /// it: the unslept amount for [`SleepFlags::Relative`], `request` itself
+    /// for [`SleepFlags::Absolute`]. [`Timespec::sleep_uninterrupted`] does
+    /// the resuming itself.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, SleepFlags, Timespec};
+    ///
+    /// let start = Timespec::now(ClockId::Monotonic).unwrap();
+    /// let mut request = Timespec::new(0, 10_000_000);
+    /// while let Some(remain) =
+    ///     Timespec::sleep(ClockId::Monotonic, SleepFlags::Relative, &request).unwrap()
+    /// {
+    ///     request = remain;
+    /// }
+    /// let end = Timespec::now(ClockId::Monotonic).unwrap();
+    /// assert!(end.sub_timespec(&start).unwrap() >= std::time::Duration::from_millis(10));
+    /// ```
+    pub fn sleep(
+        clockid: ClockId,
+        flags: SleepFlags,
+        request: &Timespec,
+    ) -> Result<Option<Self>, Errno> {
+        let mut remain = Self::zero();
+        match Self::clock_nanosleep(clockid, flags, request, &mut remain) {
+            Ok(()) => Ok(None),
+            Err(Errno::EINTR) => Ok(Some(match flags {
+                SleepFlags::Relative => remain,
+                SleepFlags::Absolute => *request,
+            })),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[inline(always)]
+    fn clock_nanosleep(
+        clockid: ClockId,
+        flags: SleepFlags,
+        request: &Timespec,
+        remain: &mut Timespec,
+    ) -> Result<(), Errno> {
+        unsafe {
+            syscall!(
+                SYS_clock_nanosleep,
+                clockid.as_raw(),
+                flags,
+                request as *const Self,
+                remain as *mut Self
+            )
+        }
+        .map(|_| ())
+    }
+
+    /// Suspends the calling thread until the clock `clockid` reaches
+    /// `deadline`, resuming on signal interruptions. Sleeping until an
+    /// absolute time isn't subject to the race of computing a remaining
+    /// amount first, and returns immediately if `deadline` is already past.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let start = Timespec::now(ClockId::Monotonic).unwrap();
+    /// Timespec::sleep_until(ClockId::Monotonic, &(start + Duration::from_millis(15))).unwrap();
+    /// assert!(start.elapsed(ClockId::Monotonic).unwrap() >= Duration::from_millis(15));
+    /// Timespec::sleep_until(ClockId::Monotonic, &start).unwrap();
+    /// ```
+    #[inline]
+    pub fn sleep_until(clockid: ClockId, deadline: &Timespec) -> Result<(), Errno> {
+        Self::sleep_uninterrupted(clockid, SleepFlags::Absolute, deadline)
+    }
+
+    /// Like [`Timespec::sleep`] but resumes the sleep when interrupted by a
+    /// signal handler, returning only once `request` has been reached.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, SleepFlags, Timespec};
+    ///
+    /// let deadline = Timespec::now(ClockId::Monotonic).unwrap() + std::time::Duration::from_millis(10);
+    /// Timespec::sleep_uninterrupted(ClockId::Monotonic, SleepFlags::Absolute, &deadline).unwrap();
+    /// assert!(Timespec::now(ClockId::Monotonic).unwrap() >= deadline);
+    /// ```
+    pub fn sleep_uninterrupted(
+        clockid: ClockId,
+        flags: SleepFlags,
+        request: &Timespec,
+    ) -> Result<(), Errno> {
+        let mut request = *request;
+        super::retry_on_eintr(|| {
+            // The kernel only writes the remainder of relative sleeps.
+            let mut remain = request;
+            let res = Self::clock_nanosleep(clockid, flags, &request, &mut remain);
+            request = remain;
+            res
+        })
+    }
+
     #[inline(always)]
     pub const fn secs(&self) -> i64 {
         self.tv_sec
@@ -240,9 +1285,70 @@ impl Timespec {
         self.tv_nsec = nsecs;
     }
 
+    /// Returns the `Timespec` as the bytes of a `__kernel_timespec` in native
+    /// byte order.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(-3, 123_456_789);
+    /// let bytes = t.to_ne_bytes();
+    /// assert_eq!(bytes[..8], (-3i64).to_ne_bytes());
+    /// assert_eq!(bytes[8..], 123_456_789i64.to_ne_bytes());
+    /// assert_eq!(bytes, unsafe { core::mem::transmute::<Timespec, [u8; 16]>(t) });
+    /// assert_eq!(Timespec::from_ne_bytes(bytes), t);
+    /// ```
+    pub fn to_ne_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.tv_sec.to_ne_bytes());
+        bytes[8..].copy_from_slice(&(self.tv_nsec as i64).to_ne_bytes());
+        bytes
+    }
+
+    /// Creates a `Timespec` from the bytes of a `__kernel_timespec` in native
+    /// byte order. Only the lower 32 bits of `tv_nsec` are kept.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let mut bytes = [0u8; 16];
+    /// bytes[..8].copy_from_slice(&42i64.to_ne_bytes());
+    /// bytes[8..].copy_from_slice(&7i64.to_ne_bytes());
+    /// assert_eq!(Timespec::from_ne_bytes(bytes), Timespec::new(42, 7));
+    /// ```
+    pub fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+        let mut secs = [0; 8];
+        let mut nsecs = [0; 8];
+        secs.copy_from_slice(&bytes[..8]);
+        nsecs.copy_from_slice(&bytes[8..]);
+        Self::new(i64::from_ne_bytes(secs), i64::from_ne_bytes(nsecs) as u32)
+    }
+
     #[inline]
     pub fn set_clock(&self) -> Result<(), Errno> {
-        unsafe { syscall!([ro] SYS_clock_settime, ClockId::Realtime, self as *const Self) }
+        self.set_clock_id(ClockId::Realtime).map_err(Errno::from)
+    }
+
+    /// Sets the clock `clockid` to this time. The kernel fails with `EINVAL`
+    /// both for clocks that are not settable and for times a settable clock
+    /// doesn't accept, [`ClockId::is_settable`] tells them apart.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockError, ClockId, Timespec};
+    ///
+    /// assert_eq!(
+    ///     Timespec::zero().set_clock_id(ClockId::Monotonic),
+    ///     Err(ClockError::InvalidClock)
+    /// );
+    /// assert_eq!(
+    ///     Timespec::new(0, 1_000_000_000).set_clock_id(ClockId::Realtime),
+    ///     Err(ClockError::OutOfRange)
+    /// );
+    /// ```
+    #[inline]
+    pub fn set_clock_id(&self, clockid: ClockId) -> Result<(), super::ClockError> {
+        unsafe { syscall!([ro] SYS_clock_settime, clockid.as_raw(), self as *const Self) }
             .map(|_| ())
+            .map_err(|err| super::ClockError::from_settime(clockid, err))
     }
 }
@@ -0,0 +1,55 @@
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use super::{ClockId, Timespec};
+use crate::Errno;
+
+/// A replacement for the clock readings of [`Timespec::now`].
+pub type NowFn = fn(ClockId) -> Result<Timespec, Errno>;
+
+static SOURCE: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Makes [`Timespec::now`], and everything built on it, return the readings
+/// of `source` instead of the real clocks, so that tests can supply
+/// deterministic times. The override is process-wide: it affects every
+/// thread until [`reset_clock_source`] is called.
+///
+/// Only [`Timespec::now_into`] and, on Linux, `Timespec::now_with_source`
+/// and the dynamic clocks of a `RawClockId` bypass it and keep reading the
+/// real clocks. The coarse readings (e.g.
+/// `Timespec::now_coarse_monotonic`) go through [`Timespec::now`] as well,
+/// asking `source` for the coarse clock first: if it fails with `EINVAL`
+/// they fall back to the fine one for the rest of the process, like they do
+/// for unsupported clocks.
+///
+/// ```
+/// use unix_clock::raw::{mock, ClockId, Timespec};
+///
+/// mock::set_clock_source(|_| Ok(Timespec::new(42, 0)));
+/// assert_eq!(Timespec::now(ClockId::Monotonic), Ok(Timespec::new(42, 0)));
+/// mock::reset_clock_source();
+/// assert_ne!(Timespec::now(ClockId::Monotonic), Ok(Timespec::new(42, 0)));
+/// ```
+#[inline]
+pub fn set_clock_source(source: NowFn) {
+    SOURCE.store(source as *mut (), Ordering::Release);
+}
+
+/// Restores the real clocks after [`set_clock_source`].
+#[inline]
+pub fn reset_clock_source() {
+    SOURCE.store(core::ptr::null_mut(), Ordering::Release);
+}
+
+/// Returns the reading of the installed source, if any.
+#[inline(always)]
+pub(crate) fn now(clockid: ClockId) -> Option<Result<Timespec, Errno>> {
+    let source = SOURCE.load(Ordering::Acquire);
+    if source.is_null() {
+        None
+    } else {
+        // Only `set_clock_source` stores a non-null pointer, and it's a
+        // `NowFn`.
+        let source = unsafe { core::mem::transmute::<*mut (), NowFn>(source) };
+        Some(source(clockid))
+    }
+}
@@ -1,10 +1,56 @@
 use core::{fmt, time::Duration};
 
+use crate::Errno;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+mod clock;
+mod clock_error;
 #[cfg_attr(target_os = "linux", path = "linux.rs")]
 #[cfg_attr(not(target_os = "linux"), path = "common.rs")]
 mod inner;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "nix")]
+mod nix_impl;
+mod normalized;
+mod reading;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod signed_duration;
+#[cfg(target_os = "linux")]
+mod snapshot;
+#[cfg(feature = "time")]
+mod time_impl;
+#[cfg(target_os = "linux")]
+mod timer;
+#[cfg(target_os = "linux")]
+mod timerfd;
+mod timeval;
+#[cfg(target_os = "linux")]
+mod timex;
 
+#[cfg(target_os = "linux")]
+pub use clock::{BoottimeClock, MonotonicRawClock};
+pub use clock::{Clock, MonotonicClock, RealtimeClock};
+pub use clock_error::ClockError;
 pub use inner::*;
+pub use normalized::NormalizedTimespec;
+pub use reading::Reading;
+#[cfg(feature = "serde")]
+pub use serde_impl::timespec_nanos;
+pub use signed_duration::{detect_step, step_realtime, SignedDuration};
+#[cfg(target_os = "linux")]
+pub use snapshot::ClockSnapshot;
+#[cfg(target_os = "linux")]
+pub use timer::Timer;
+#[cfg(target_os = "linux")]
+pub use timerfd::{Itimerspec, TimerFd, TimerFdFlags};
+pub use timeval::Timeval;
+#[cfg(target_os = "linux")]
+pub use timex::*;
 
 #[cfg(any(
     target_os = "macos",
@@ -23,15 +69,567 @@ pub(crate) const INSTANT_CLOCKID: ClockId = ClockId::Monotonic;
 
 pub(crate) const SYSTEM_TIME_CLOCKID: ClockId = ClockId::Realtime;
 
+impl ClockId {
+    /// Returns the raw `clockid_t` value of this clock.
+    #[inline]
+    pub const fn as_raw(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Returns `true` if the clock can be set with
+    /// [`Timespec::set_clock_id`]. Only the realtime clock is settable, the
+    /// other ones are either derived from it or not writable at all.
+    ///
+    /// On Linux `CLOCK_TAI` is set indirectly, through its offset from the
+    /// realtime clock (the `ADJ_TAI` mode of `adjtimex`, see `adjtime` and
+    /// `tai_utc_offset`), so `is_settable` is `false` for
+    /// [`ClockId::InternationalAtomicTime`]: `clock_settime` rejects it.
+    ///
+    /// ```
+    /// use unix_clock::raw::ClockId;
+    ///
+    /// assert!(ClockId::Realtime.is_settable());
+    /// assert!(!ClockId::Monotonic.is_settable());
+    /// # #[cfg(target_os = "linux")]
+    /// assert!(!ClockId::InternationalAtomicTime.is_settable());
+    /// ```
+    #[inline]
+    pub const fn is_settable(&self) -> bool {
+        matches!(*self, Self::Realtime)
+    }
+
+    /// Returns `true` if the clock measures CPU time consumed by the calling
+    /// process or thread.
+    #[inline]
+    pub const fn is_cpu_time(&self) -> bool {
+        matches!(*self, Self::ProcessCputimeId | Self::ThreadCputimeId)
+    }
+
+    /// Returns every clock known on the current platform, in declaration
+    /// order (the discriminant order on Linux). The dynamic clocks derived
+    /// from a pid or a file descriptor are not listed.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let all = ClockId::all();
+    /// # #[cfg(target_os = "linux")]
+    /// assert_eq!(all.len(), 11);
+    /// for (i, a) in all.iter().enumerate() {
+    ///     assert!(all[i + 1..].iter().all(|b| a != b));
+    /// }
+    ///
+    /// let supported = all.iter().filter(|&&id| Timespec::is_clock_supported(id));
+    /// assert!(supported.count() >= 2);
+    /// ```
+    #[inline]
+    pub const fn all() -> &'static [ClockId] {
+        Self::ALL
+    }
+
+    /// Parses the name of a `CLOCK_*` constant, ignoring the case, the
+    /// inverse of [`ClockId::as_clock_str`].
+    ///
+    /// ```
+    /// use unix_clock::raw::ClockId;
+    ///
+    /// for &id in ClockId::all() {
+    ///     let name = id.as_clock_str();
+    ///     assert_eq!(ClockId::from_clock_str(name), Some(id));
+    ///     assert_eq!(ClockId::from_clock_str(&name.to_lowercase()), Some(id));
+    /// }
+    /// assert_eq!(ClockId::from_clock_str("clock_monotonic"), Some(ClockId::Monotonic));
+    /// assert_eq!(ClockId::from_clock_str("CLOCK_NOPE"), None);
+    /// assert_eq!(ClockId::from_clock_str("MONOTONIC"), None);
+    /// ```
+    pub fn from_clock_str(s: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|id| id.as_str().eq_ignore_ascii_case(s))
+            .copied()
+    }
+
+    /// Returns the name of the `CLOCK_*` constant of this clock, same as
+    /// [`ClockId::as_str`], the inverse of [`ClockId::from_clock_str`].
+    ///
+    /// ```
+    /// use unix_clock::raw::ClockId;
+    ///
+    /// assert_eq!(ClockId::Realtime.as_clock_str(), "CLOCK_REALTIME");
+    /// ```
+    #[inline]
+    pub const fn as_clock_str(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
+/// Writes the name of the `CLOCK_*` constant, see [`ClockId::as_str`].
+impl fmt::Display for ClockId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Validates a raw `clockid_t` value, failing with `EINVAL` if it's not a
+/// known clock.
+///
+/// ```
+/// # #[cfg(target_os = "linux")] {
+/// use unix_clock::{raw::ClockId, Errno};
+///
+/// assert_eq!(ClockId::try_from(11), Ok(ClockId::InternationalAtomicTime));
+/// assert_eq!(ClockId::try_from(10), Err(Errno::EINVAL));
+/// # }
+/// ```
+impl TryFrom<i32> for ClockId {
+    type Error = Errno;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::ALL
+            .iter()
+            .find(|id| id.as_raw() == value)
+            .copied()
+            .ok_or(Errno::EINVAL)
+    }
+}
+
 const NSEC_PER_SEC: u64 = 1_000_000_000;
 const I64_MAX: u64 = 9_223_372_036_854_775_807;
 
+/// Calls `f` again as long as it fails with `EINTR`, for the blocking calls
+/// but [`Timespec::sleep`] which hands the remainder back to the caller.
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn retry_on_eintr<T, F: FnMut() -> Result<T, Errno>>(mut f: F) -> Result<T, Errno> {
+    loop {
+        match f() {
+            Err(Errno::EINTR) => continue,
+            res => return res,
+        }
+    }
+}
+
+/// How [`Timespec::from_secs_f64_rounding`] rounds to the nanosecond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RoundMode {
+    /// To the nearest nanosecond, ties away from zero.
+    Nearest,
+    /// To the nanosecond below, towards negative infinity.
+    Down,
+    /// To the nanosecond above, towards positive infinity.
+    Up,
+    /// To the nanosecond closer to zero.
+    TowardZero,
+}
+
 impl Timespec {
+    /// The smallest representable `Timespec`.
+    pub const MIN: Self = Self::new(i64::MIN, 0);
+    /// The largest representable `Timespec`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert!(Timespec::MAX > Timespec::MIN);
+    /// assert_eq!(Timespec::MAX.saturating_add_duration(&Duration::from_nanos(1)), Timespec::MAX);
+    /// assert_eq!(Timespec::MIN.saturating_sub_duration(&Duration::from_nanos(1)), Timespec::MIN);
+    /// ```
+    pub const MAX: Self = Self::new(i64::MAX, NSEC_PER_SEC as u32 - 1);
+    /// The Unix epoch, same as [`Timespec::zero`].
+    pub const UNIX_EPOCH: Self = Self::zero();
+
     #[inline(always)]
     pub const fn zero() -> Self {
         Self::new(0, 0)
     }
 
+    /// Creates a new `Timespec` carrying the nanoseconds exceeding a second
+    /// into `secs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting seconds overflow an `i64`.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::from_parts(0, 2_000_000_000), Timespec::new(2, 0));
+    /// ```
+    pub const fn from_parts(secs: i64, nsecs: u64) -> Self {
+        let secs = match secs.checked_add((nsecs / NSEC_PER_SEC) as i64) {
+            Some(secs) => secs,
+            None => panic!("overflow when normalizing timespec"),
+        };
+        Self::new(secs, (nsecs % NSEC_PER_SEC) as u32)
+    }
+
+    /// Creates a new `Timespec` from seconds and a signed number of
+    /// nanoseconds, carrying them into `secs` so that the stored nanoseconds
+    /// are in `0..1_000_000_000`, or `None` if the seconds overflow.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::checked_from_signed(0, -1), Some(Timespec::new(-1, 999_999_999)));
+    /// assert_eq!(
+    ///     Timespec::checked_from_signed(1, -1_500_000_000),
+    ///     Some(Timespec::new(-1, 500_000_000))
+    /// );
+    /// assert_eq!(Timespec::checked_from_signed(0, 1_000_000_001), Some(Timespec::new(1, 1)));
+    /// assert_eq!(Timespec::checked_from_signed(i64::MIN, -1), None);
+    /// assert_eq!(Timespec::checked_from_signed(i64::MAX, 1_000_000_000), None);
+    /// ```
+    pub const fn checked_from_signed(secs: i64, nsecs: i64) -> Option<Self> {
+        match secs.checked_add(nsecs.div_euclid(NSEC_PER_SEC as i64)) {
+            Some(secs) => Some(Self::new(
+                secs,
+                nsecs.rem_euclid(NSEC_PER_SEC as i64) as u32,
+            )),
+            None => None,
+        }
+    }
+
+    /// Same as [`Timespec::checked_from_signed`] but clamps to
+    /// [`Timespec::MIN`] or [`Timespec::MAX`] instead of overflowing.
+    pub(crate) const fn saturating_from_signed(secs: i64, nsecs: i64) -> Self {
+        match Self::checked_from_signed(secs, nsecs) {
+            Some(t) => t,
+            None if nsecs < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Carries the nanoseconds exceeding a second into the seconds, see
+    /// [`Timespec::from_parts`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting seconds overflow an `i64`.
+    pub fn normalize(&mut self) {
+        *self = Self::from_parts(self.secs(), self.nsecs() as u64);
+    }
+
+    /// Returns `true` if the nanoseconds are less than a second. Every
+    /// constructor other than [`Timespec::new`] and the setters produces
+    /// normalized values.
+    #[inline]
+    pub const fn is_normalized(&self) -> bool {
+        (self.nsecs() as u64) < NSEC_PER_SEC
+    }
+
+    /// Returns `true` if `self` is exactly the clock's origin.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let zero = Timespec::zero();
+    /// assert!(zero.is_zero() && !zero.is_positive() && !zero.is_negative());
+    ///
+    /// let t = Timespec::new(0, 1);
+    /// assert!(!t.is_zero() && t.is_positive() && !t.is_negative());
+    ///
+    /// let t = Timespec::new(-1, 999_999_999);
+    /// assert!(!t.is_zero() && !t.is_positive() && t.is_negative());
+    ///
+    /// let t = Timespec::new(-1, 0);
+    /// assert!(!t.is_zero() && !t.is_positive() && t.is_negative());
+    ///
+    /// let t = Timespec::new(1, 0);
+    /// assert!(!t.is_zero() && t.is_positive() && !t.is_negative());
+    ///
+    /// let t = Timespec::new(-1, 1_000_000_000);
+    /// assert!(t.is_zero() && !t.is_positive() && !t.is_negative());
+    /// ```
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.as_nanos() == 0
+    }
+
+    /// Returns `true` if `self` is after the clock's origin, taking both the
+    /// seconds and the nanoseconds into account.
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        self.as_nanos() > 0
+    }
+
+    /// Returns `true` if `self` is before the clock's origin, taking both the
+    /// seconds and the nanoseconds into account.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.as_nanos() < 0
+    }
+
+    /// Compares the instants represented by `self` and `other` even if they
+    /// are not normalized. The [`Ord`] impl compares the raw fields instead,
+    /// so denormalized values may not follow the chronological order.
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let a = Timespec::new(1, 1_500_000_000);
+    /// let b = Timespec::new(2, 500_000_000);
+    /// assert!(!a.is_normalized() && b.is_normalized());
+    /// assert_ne!(a, b);
+    /// assert_eq!(a.cmp(&b), Ordering::Less);
+    /// assert_eq!(a.cmp_normalized(&b), Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn cmp_normalized(&self, other: &Timespec) -> core::cmp::Ordering {
+        self.as_nanos().cmp(&other.as_nanos())
+    }
+
+    /// Returns this `Timespec` as a [`Duration`] since the clock's origin, or
+    /// `None` if it lies before it.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(
+    ///     Timespec::new(i64::MAX, 5).as_duration(),
+    ///     Some(Duration::new(i64::MAX as u64, 5))
+    /// );
+    /// assert_eq!(Timespec::new(-1, 5).as_duration(), None);
+    /// ```
+    pub const fn as_duration(&self) -> Option<Duration> {
+        if self.secs() < 0 {
+            None
+        } else {
+            Some(Duration::new(self.secs() as u64, self.nsecs()))
+        }
+    }
+
+    /// Creates a `Timespec` from a [`Duration`] since the clock's origin, or
+    /// `None` if its seconds don't fit in an `i64`. The [`From`] impl clamps
+    /// to [`Timespec::MAX`] instead.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(
+    ///     Timespec::from_duration(Duration::new(i64::MAX as u64, 5)),
+    ///     Some(Timespec::new(i64::MAX, 5))
+    /// );
+    /// assert_eq!(Timespec::from_duration(Duration::new(i64::MAX as u64 + 1, 0)), None);
+    /// ```
+    pub const fn from_duration(d: Duration) -> Option<Self> {
+        if d.as_secs() > I64_MAX {
+            None
+        } else {
+            Some(Self::new(d.as_secs() as i64, d.subsec_nanos()))
+        }
+    }
+
+    /// Returns the total number of nanoseconds represented by this
+    /// `Timespec`.
+    #[inline]
+    pub const fn as_nanos(&self) -> i128 {
+        self.secs() as i128 * NSEC_PER_SEC as i128 + self.nsecs() as i128
+    }
+
+    /// Creates a `Timespec` from a total number of nanoseconds, or `None` if
+    /// the seconds don't fit in an `i64`. Negative values are floored, so the
+    /// nanoseconds are always positive.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::from_nanos(-500).unwrap();
+    /// assert_eq!(t, Timespec::new(-1, 999_999_500));
+    /// assert_eq!(t.as_nanos(), -500);
+    /// assert_eq!(Timespec::from_nanos(i128::MIN), None);
+    /// ```
+    pub const fn from_nanos(nanos: i128) -> Option<Self> {
+        let secs = nanos.div_euclid(NSEC_PER_SEC as i128);
+        if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+            None
+        } else {
+            Some(Self::new(
+                secs as i64,
+                nanos.rem_euclid(NSEC_PER_SEC as i128) as u32,
+            ))
+        }
+    }
+
+    /// Returns the total number of whole milliseconds, floored toward
+    /// negative infinity like [`Timespec::as_nanos`].
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::new(1, 999_999).as_millis(), 1_000);
+    /// assert_eq!(Timespec::from_nanos(-1).unwrap().as_millis(), -1);
+    /// ```
+    #[inline]
+    pub const fn as_millis(&self) -> i128 {
+        self.as_nanos().div_euclid(1_000_000)
+    }
+
+    /// Returns the total number of whole microseconds, floored toward
+    /// negative infinity like [`Timespec::as_nanos`].
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::new(1, 999).as_micros(), 1_000_000);
+    /// assert_eq!(Timespec::from_nanos(-1_001).unwrap().as_micros(), -2);
+    /// ```
+    #[inline]
+    pub const fn as_micros(&self) -> i128 {
+        self.as_nanos().div_euclid(1_000)
+    }
+
+    /// Creates a `Timespec` from a whole number of seconds.
+    #[inline]
+    pub const fn from_secs(secs: i64) -> Self {
+        Self::new(secs, 0)
+    }
+
+    /// Creates a `Timespec` from a total number of milliseconds, rounding the
+    /// seconds towards negative infinity like [`Timespec::from_nanos`]. Any
+    /// `i64` number of milliseconds fits, so this works in `const` contexts.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// const T: Timespec = Timespec::from_millis(1_500);
+    /// assert_eq!(T, Timespec::new(1, 500_000_000));
+    /// assert_eq!(Timespec::from_millis(-1_500), Timespec::new(-2, 500_000_000));
+    /// assert_eq!(Timespec::from_millis(-1_000), Timespec::from_secs(-1));
+    /// assert_eq!(
+    ///     Timespec::from_millis(i64::MIN),
+    ///     Timespec::from_nanos(i64::MIN as i128 * 1_000_000).unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_millis(millis: i64) -> Self {
+        Self::new(
+            millis.div_euclid(1_000),
+            millis.rem_euclid(1_000) as u32 * 1_000_000,
+        )
+    }
+
+    /// Creates a `Timespec` from a total number of microseconds, or `None` if
+    /// the seconds don't fit in an `i64`.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::from_micros(-1), Some(Timespec::new(-1, 999_999_000)));
+    /// assert_eq!(Timespec::from_micros(i128::MAX), None);
+    /// ```
+    pub const fn from_micros(micros: i128) -> Option<Self> {
+        match micros.checked_mul(1_000) {
+            Some(nanos) => Self::from_nanos(nanos),
+            None => None,
+        }
+    }
+
+    /// Returns the number of seconds represented by this `Timespec` as an
+    /// `f64`.
+    #[inline]
+    pub fn as_secs_f64(&self) -> f64 {
+        self.secs() as f64 + self.nsecs() as f64 / NSEC_PER_SEC as f64
+    }
+
+    /// Returns the number of seconds represented by this `Timespec` as an
+    /// `f32`.
+    #[inline]
+    pub fn as_secs_f32(&self) -> f32 {
+        self.secs() as f32 + self.nsecs() as f32 / NSEC_PER_SEC as f32
+    }
+
+    /// Creates a `Timespec` from a number of seconds, truncating to the
+    /// nanosecond below. Returns `None` if `secs` is not finite or its
+    /// integral part doesn't fit in an `i64`.
+    ///
+    /// Negative values are floored as well: less than a nanosecond below
+    /// zero is the last nanosecond of second `-1`, not zero. Use
+    /// [`RoundMode::TowardZero`] with [`Timespec::from_secs_f64_rounding`]
+    /// to get zero instead.
+    ///
+    /// ```
+    /// use unix_clock::raw::{RoundMode, Timespec};
+    ///
+    /// assert_eq!(Timespec::from_secs_f64(-0.5), Some(Timespec::new(-1, 500_000_000)));
+    /// assert_eq!(Timespec::from_secs_f64(-1e-12), Some(Timespec::new(-1, 999_999_999)));
+    /// assert_eq!(
+    ///     Timespec::from_secs_f64_rounding(-1e-12, RoundMode::TowardZero),
+    ///     Some(Timespec::zero())
+    /// );
+    /// let t = Timespec::from_secs_f64(1.7).unwrap();
+    /// assert!((t.as_secs_f64() - 1.7).abs() < 1e-9);
+    /// assert_eq!(Timespec::from_secs_f64(f64::NAN), None);
+    /// assert_eq!(Timespec::from_secs_f64(1e19), None);
+    /// ```
+    #[inline]
+    pub fn from_secs_f64(secs: f64) -> Option<Self> {
+        Self::from_secs_f64_rounding(secs, RoundMode::Down)
+    }
+
+    /// Like [`Timespec::from_secs_f64`], rounding the fractional nanoseconds
+    /// according to `mode` instead of always truncating them down.
+    ///
+    /// ```
+    /// use unix_clock::raw::{RoundMode, Timespec};
+    ///
+    /// let half = 0.000_000_000_5;
+    /// let round = |s, mode| Timespec::from_secs_f64_rounding(s, mode).unwrap();
+    /// assert_eq!(round(half, RoundMode::Down), Timespec::zero());
+    /// assert_eq!(round(half, RoundMode::TowardZero), Timespec::zero());
+    /// assert_eq!(round(half, RoundMode::Up), Timespec::new(0, 1));
+    /// assert_eq!(round(half, RoundMode::Nearest), Timespec::new(0, 1));
+    ///
+    /// let below = -0.000_000_000_25;
+    /// assert_eq!(round(below, RoundMode::Down), Timespec::new(-1, 999_999_999));
+    /// assert_eq!(round(below, RoundMode::TowardZero), Timespec::zero());
+    /// assert_eq!(round(below, RoundMode::Up), Timespec::zero());
+    /// assert_eq!(round(below, RoundMode::Nearest), Timespec::zero());
+    ///
+    /// assert_eq!(round(1.999_999_999_9, RoundMode::Nearest), Timespec::new(2, 0));
+    /// assert_eq!(round(2.0, RoundMode::Up), Timespec::new(2, 0));
+    /// assert_eq!(Timespec::from_secs_f64_rounding(f64::INFINITY, RoundMode::Down), None);
+    /// ```
+    pub fn from_secs_f64_rounding(secs: f64, mode: RoundMode) -> Option<Self> {
+        // 2^63, exactly representable unlike `i64::MAX`.
+        const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+
+        if !(-LIMIT..LIMIT).contains(&secs) {
+            return None;
+        }
+        let mut whole = secs as i64;
+        let mut frac = secs - whole as f64;
+        if frac < 0.0 {
+            whole -= 1;
+            frac += 1.0;
+        }
+        let nanos = frac * NSEC_PER_SEC as f64;
+        let down = nanos as u64;
+        let rem = nanos - down as f64;
+        let up = match mode {
+            RoundMode::Down => false,
+            RoundMode::Up => rem > 0.0,
+            RoundMode::TowardZero => secs < 0.0 && rem > 0.0,
+            // Ties are rounded away from zero, like `f64::round`.
+            RoundMode::Nearest => rem > 0.5 || (rem == 0.5 && secs >= 0.0),
+        };
+        let nsecs = down + up as u64;
+        if nsecs >= NSEC_PER_SEC {
+            Some(Self::new(whole.checked_add(1)?, 0))
+        } else {
+            Some(Self::new(whole, nsecs as u32))
+        }
+    }
+
+    /// Same as [`Timespec::from_secs_f64`] for an `f32`.
+    #[inline]
+    pub fn from_secs_f32(secs: f32) -> Option<Self> {
+        Self::from_secs_f64(secs as f64)
+    }
+
     #[inline(always)]
     pub const fn seconds(&self) -> i64 {
         self.secs()
@@ -57,20 +655,78 @@ impl Timespec {
         self.set_nsecs(nsecs)
     }
 
+    /// Same as [`Timespec::secs`].
+    #[inline(always)]
+    pub const fn whole_seconds(&self) -> i64 {
+        self.secs()
+    }
+
+    /// Returns the fractional part of the `Timespec` as a [`Duration`].
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::new(5, 250).subsec_duration(), Duration::from_nanos(250));
+    /// assert_eq!(Timespec::new(-1, 250).whole_seconds(), -1);
+    /// ```
+    #[inline(always)]
+    pub const fn subsec_duration(&self) -> Duration {
+        Duration::from_nanos(self.nsecs() as u64)
+    }
+
     #[inline(always)]
     pub fn set_nanoseconds(&mut self, nsecs: u32) {
         self.set_nsecs(nsecs)
     }
 
+    /// Returns the amount of time elapsed from `other` to `self` in `Ok`, or
+    /// from `self` to `other` in `Err` if `other` is later. It never fails
+    /// for normalized values, the whole range fits in a [`Duration`].
+    ///
+    /// Checked addition and subtraction of a [`Duration`] round-trip, and the
+    /// subtraction is antisymmetric, across the edges of the range:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let secs = [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+    /// let usecs = [0, 1, i64::MAX as u64, i64::MAX as u64 + 1, u64::MAX];
+    /// let nsecs = [0, 1, 499_999_999, 500_000_000, 999_999_999];
+    ///
+    /// let ts = secs.iter().flat_map(|&s| nsecs.iter().map(move |&n| Timespec::new(s, n)));
+    /// let ds = usecs.iter().flat_map(|&s| nsecs.iter().map(move |&n| Duration::new(s, n)));
+    ///
+    /// for x in ts.clone() {
+    ///     for d in ds.clone() {
+    ///         if let Some(y) = x.checked_add_duration(&d) {
+    ///             assert_eq!(y.checked_sub_duration(&d), Some(x));
+    ///             assert_eq!(y.sub_timespec(&x), Ok(d));
+    ///         }
+    ///         if let Some(y) = x.checked_sub_duration(&d) {
+    ///             assert_eq!(y.checked_add_duration(&d), Some(x));
+    ///         }
+    ///     }
+    ///     for y in ts.clone() {
+    ///         let swapped = match y.sub_timespec(&x) {
+    ///             Ok(d) if d.is_zero() => Ok(d),
+    ///             Ok(d) => Err(d),
+    ///             Err(d) => Ok(d),
+    ///         };
+    ///         assert_eq!(x.sub_timespec(&y), swapped);
+    ///     }
+    /// }
+    /// ```
     pub fn sub_timespec(&self, other: &Timespec) -> Result<Duration, Duration> {
         if self >= other {
             // NOTE(eddyb) two aspects of this `if`-`else` are required for LLVM
             // to optimize it into a branchless form (see also #75545):
             //
-            // 1. `self.secs() - other.secs()` shows up as a common expression
-            //    in both branches, i.e. the `else` must have its `- 1`
-            //    subtraction after the common one, not interleaved with it
-            //    (it used to be `self.secs() - 1 - other.secs()`)
+            // 1. `self.secs().wrapping_sub(other.secs())` shows up as a
+            //    common expression in both branches, i.e. the `else` must have
+            //    its `- 1` subtraction after the common one, not interleaved
+            //    with it (it used to be `self.secs() - 1 - other.secs()`)
             //
             // 2. the `Duration::new` call (or any other additional complexity)
             //    is outside of the `if`-`else`, not duplicated in both branches
@@ -79,12 +735,12 @@ impl Timespec {
             // directly expresses the lower-cost behavior we want from it.
             let (secs, nsecs) = if self.nsecs() >= other.nsecs() {
                 (
-                    (self.secs() - other.secs()) as u64,
+                    self.secs().wrapping_sub(other.secs()) as u64,
                     self.nsecs() - other.nsecs(),
                 )
             } else {
                 (
-                    (self.secs() - other.secs() - 1) as u64,
+                    self.secs().wrapping_sub(other.secs()).wrapping_sub(1) as u64,
                     self.nsecs() + (NSEC_PER_SEC as u32) - other.nsecs(),
                 )
             };
@@ -98,12 +754,352 @@ impl Timespec {
         }
     }
 
-    pub fn checked_add_duration(&self, other: &Duration) -> Option<Timespec> {
+    /// Returns the amount of time elapsed from `earlier` to `self`, or `None`
+    /// if `earlier` is later than `self`, like
+    /// [`Instant::checked_duration_since`](crate::Instant::checked_duration_since).
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let a = Timespec::new(1, 500_000_000);
+    /// let b = Timespec::new(2, 0);
+    /// assert_eq!(a.checked_duration_since(&a), Some(Duration::ZERO));
+    /// assert_eq!(b.checked_duration_since(&a), Some(Duration::from_millis(500)));
+    /// assert_eq!(a.checked_duration_since(&b), None);
+    /// ```
+    #[inline]
+    pub fn checked_duration_since(&self, earlier: &Timespec) -> Option<Duration> {
+        self.sub_timespec(earlier).ok()
+    }
+
+    /// Returns the amount of time elapsed since `epoch`, a reading of the
+    /// same clock taken as the origin of another time scale, or `None` if
+    /// `self` is before it. Generalizes [`crate::realtime_since_epoch`] to
+    /// other bases.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// // 1980-01-06T00:00:00Z
+    /// let gps_epoch = Timespec::new(315_964_800, 0);
+    /// let t = Timespec::new(315_964_800 + 86_400, 250_000_000);
+    /// assert_eq!(t.since_epoch(&gps_epoch), Some(Duration::new(86_400, 250_000_000)));
+    /// assert_eq!(gps_epoch.since_epoch(&gps_epoch), Some(Duration::ZERO));
+    /// assert_eq!(Timespec::zero().since_epoch(&gps_epoch), None);
+    /// ```
+    #[inline]
+    pub fn since_epoch(&self, epoch: &Timespec) -> Option<Duration> {
+        self.checked_duration_since(epoch)
+    }
+
+    /// Reads the current value of the clock `clockid` assuming it can't fail,
+    /// letting the compiler drop the error handling from hot loops.
+    ///
+    /// # Safety
+    ///
+    /// `clockid` must be a clock supported by the running system, reading it
+    /// must not fail or the behavior is undefined.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let before = Timespec::now(ClockId::Monotonic).unwrap();
+    /// let now = unsafe { Timespec::now_unchecked(ClockId::Monotonic) };
+    /// assert!(before <= now && now <= Timespec::now(ClockId::Monotonic).unwrap());
+    /// ```
+    #[inline(always)]
+    pub unsafe fn now_unchecked(clockid: ClockId) -> Self {
+        match Self::now(clockid) {
+            Ok(t) => t,
+            Err(_) => core::hint::unreachable_unchecked(),
+        }
+    }
+
+    /// Reads the clocks `a` and `b` back to back. The two readings are not
+    /// atomic, but they are as close as two consecutive
+    /// [`Timespec::now`] calls allow, which is useful to correlate clocks.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let (mono, real) = Timespec::now_pair(ClockId::Monotonic, ClockId::Realtime).unwrap();
+    /// assert!(mono > Timespec::zero() && real > Timespec::zero());
+    /// ```
+    #[inline]
+    pub fn now_pair(a: ClockId, b: ClockId) -> Result<(Timespec, Timespec), Errno> {
+        let a = Self::now(a)?;
+        let b = Self::now(b)?;
+        Ok((a, b))
+    }
+
+    /// Same as [`Timespec::now`] but tries again on `EINTR` and `EAGAIN`, up
+    /// to `attempts` reads in total (at least one), returning the last error
+    /// when they're exhausted. Other errors are returned right away.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// // A successful read doesn't need a second attempt.
+    /// assert!(Timespec::now_retry(ClockId::Monotonic, 1).is_ok());
+    /// ```
+    pub fn now_retry(clockid: ClockId, attempts: u32) -> Result<Timespec, Errno> {
+        let mut left = attempts.max(1);
+        loop {
+            left -= 1;
+            match Self::now(clockid) {
+                Err(Errno::EINTR | Errno::EAGAIN) if left > 0 => continue,
+                res => return res,
+            }
+        }
+    }
+
+    /// Returns `true` if the clock `clockid` is usable, i.e. its
+    /// [`Timespec::resolution`] can be read and is not zero, which is a
+    /// cheap way to probe clocks at startup.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// for clockid in [
+    ///     ClockId::Realtime,
+    ///     ClockId::Monotonic,
+    ///     ClockId::ProcessCputimeId,
+    ///     ClockId::ThreadCputimeId,
+    /// ] {
+    ///     assert!(Timespec::is_clock_supported(clockid), "{:?}", clockid);
+    /// }
+    /// # #[cfg(target_os = "linux")]
+    /// # for clockid in [ClockId::MonotonicRaw, ClockId::Boottime, ClockId::MonotonicCoarse] {
+    /// #     assert!(Timespec::is_clock_supported(clockid), "{:?}", clockid);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_clock_supported(clockid: ClockId) -> bool {
+        matches!(Self::resolution(clockid), Ok(res) if !res.is_zero())
+    }
+
+    /// Returns the amount of time elapsed on the clock `clockid` since this
+    /// reading, or zero duration if the clock went backwards.
+    ///
+    /// ```
+    /// use std::{thread::sleep, time::Duration};
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let start = Timespec::now(ClockId::Monotonic).unwrap();
+    /// sleep(Duration::from_millis(10));
+    /// assert!(start.elapsed(ClockId::Monotonic).unwrap() >= Duration::from_millis(10));
+    /// ```
+    pub fn elapsed(&self, clockid: ClockId) -> Result<Duration, Errno> {
+        Ok(Timespec::now(clockid)?
+            .sub_timespec(self)
+            .unwrap_or_default())
+    }
+
+    /// Returns the amount of time left on the clock `clockid` until this
+    /// deadline, or zero duration if it's already past. This is the inverse
+    /// of [`Timespec::elapsed`].
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let now = Timespec::now(ClockId::Monotonic).unwrap();
+    /// let deadline = now + Duration::from_secs(60);
+    /// let remaining = deadline.remaining(ClockId::Monotonic).unwrap();
+    /// assert!(remaining > Duration::from_secs(59) && remaining <= Duration::from_secs(60));
+    /// assert_eq!(now.remaining(ClockId::Monotonic).unwrap(), Duration::ZERO);
+    /// ```
+    pub fn remaining(&self, clockid: ClockId) -> Result<Duration, Errno> {
+        Ok(self
+            .sub_timespec(&Timespec::now(clockid)?)
+            .unwrap_or_default())
+    }
+
+    /// Returns the magnitude of the difference between `self` and `other`,
+    /// regardless of which one is later.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let a = Timespec::new(1, 900_000_000);
+    /// let b = Timespec::new(3, 100_000_000);
+    /// assert_eq!(a.abs_diff(&b), Duration::from_millis(1_200));
+    /// assert_eq!(a.abs_diff(&b), b.abs_diff(&a));
+    /// assert_eq!(a.abs_diff(&a), Duration::ZERO);
+    /// ```
+    #[inline]
+    pub fn abs_diff(&self, other: &Timespec) -> Duration {
+        self.sub_timespec(other).unwrap_or_else(|d| d)
+    }
+
+    /// Returns `true` if `self` and `other` are at most `tolerance` apart.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let a = Timespec::new(1, 999_999_000);
+    /// let b = Timespec::new(2, 1_000);
+    /// assert!(a.approx_eq(&b, Duration::from_micros(2)));
+    /// assert!(b.approx_eq(&a, Duration::from_micros(2)));
+    /// assert!(!a.approx_eq(&b, Duration::from_nanos(1_999)));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Timespec, tolerance: Duration) -> bool {
+        self.abs_diff(other) <= tolerance
+    }
+
+    /// Returns `true` if the time since the clock's origin is at least `d`,
+    /// without building a `Timespec` out of it.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(1, 0);
+    /// assert!(t.ge_duration(&Duration::from_secs(1)));
+    /// assert!(!t.ge_duration(&Duration::new(1, 1)));
+    /// assert!(t.lt_duration(&Duration::new(1, 1)));
+    /// assert!(!Timespec::new(0, 999_999_999).ge_duration(&Duration::from_secs(1)));
+    /// assert!(Timespec::new(-1, 0).lt_duration(&Duration::ZERO));
+    /// ```
+    #[inline]
+    pub const fn ge_duration(&self, d: &Duration) -> bool {
+        self.as_nanos() >= d.as_nanos() as i128
+    }
+
+    /// Returns `true` if the time since the clock's origin is less than `d`,
+    /// the opposite of [`Timespec::ge_duration`].
+    #[inline]
+    pub const fn lt_duration(&self, d: &Duration) -> bool {
+        !self.ge_duration(d)
+    }
+
+    /// Returns an iterator over `self`, `self + interval`,
+    /// `self + 2 * interval`, ... stopping on overflow. Every tick is computed
+    /// from `self` so no error accumulates over time.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let start = Timespec::new(10, 0);
+    /// let mut ticks = start.ticks(Duration::from_millis(1));
+    /// assert_eq!(ticks.next(), Some(start));
+    /// assert_eq!(ticks.nth(999), Some(Timespec::new(11, 0)));
+    ///
+    /// assert_eq!(Timespec::MAX.ticks(Duration::from_nanos(1)).count(), 1);
+    /// ```
+    pub fn ticks(&self, interval: Duration) -> impl Iterator<Item = Timespec> {
+        let start = self.as_nanos();
+        let interval = interval.as_nanos() as i128;
+        (0u64..).map_while(move |n| {
+            (n as i128)
+                .checked_mul(interval)
+                .and_then(|offset| start.checked_add(offset))
+                .and_then(Self::from_nanos)
+        })
+    }
+
+    /// Rounds down to a multiple of `granularity` since the clock's origin,
+    /// or returns `None` if `granularity` is zero or the result overflows.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(5, 750_000_000);
+    /// assert_eq!(t.floor_to(Duration::from_secs(1)), Some(Timespec::new(5, 0)));
+    /// assert_eq!(t.ceil_to(Duration::from_secs(1)), Some(Timespec::new(6, 0)));
+    /// assert_eq!(t.round_to(Duration::from_secs(1)), Some(Timespec::new(6, 0)));
+    /// assert_eq!(t.round_to(Duration::from_millis(100)), Some(Timespec::new(5, 800_000_000)));
+    /// assert_eq!(
+    ///     Timespec::new(-1, 250_000_000).floor_to(Duration::from_secs(1)),
+    ///     Some(Timespec::new(-1, 0))
+    /// );
+    /// assert_eq!(Timespec::MAX.ceil_to(Duration::from_secs(1)), None);
+    /// assert_eq!(t.floor_to(Duration::ZERO), None);
+    /// ```
+    pub const fn floor_to(&self, granularity: Duration) -> Option<Timespec> {
+        let g = granularity.as_nanos() as i128;
+        if g == 0 {
+            return None;
+        }
+        let nanos = self.as_nanos();
+        Self::from_nanos(nanos - nanos.rem_euclid(g))
+    }
+
+    /// Rounds up to a multiple of `granularity` since the clock's origin, or
+    /// returns `None` if `granularity` is zero or the result overflows.
+    pub const fn ceil_to(&self, granularity: Duration) -> Option<Timespec> {
+        let g = granularity.as_nanos() as i128;
+        if g == 0 {
+            return None;
+        }
+        let nanos = self.as_nanos();
+        match nanos.rem_euclid(g) {
+            0 => Self::from_nanos(nanos),
+            rem => Self::from_nanos(nanos - rem + g),
+        }
+    }
+
+    /// Rounds to the nearest multiple of `granularity` since the clock's
+    /// origin, halfway values rounding up. Returns `None` if `granularity`
+    /// is zero or the result overflows.
+    pub const fn round_to(&self, granularity: Duration) -> Option<Timespec> {
+        let g = granularity.as_nanos() as i128;
+        if g == 0 {
+            return None;
+        }
+        if self.as_nanos().rem_euclid(g) * 2 >= g {
+            self.ceil_to(granularity)
+        } else {
+            self.floor_to(granularity)
+        }
+    }
+
+    /// Returns `-self`, or `None` if it overflows, which only happens for
+    /// [`Timespec::MIN`]. The result is normalized, borrowing a second when
+    /// there are nanoseconds.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(1, 500_000_000);
+    /// assert_eq!(t.checked_neg(), Some(Timespec::new(-2, 500_000_000)));
+    /// assert_eq!(t.checked_neg().and_then(|t| t.checked_neg()), Some(t));
+    /// assert_eq!(Timespec::new(3, 0).checked_neg(), Some(Timespec::new(-3, 0)));
+    /// assert_eq!(Timespec::MIN.checked_neg(), None);
+    /// assert_eq!(
+    ///     Timespec::new(i64::MIN, 1).checked_neg(),
+    ///     Some(Timespec::new(i64::MAX, 999_999_999))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn checked_neg(&self) -> Option<Timespec> {
+        Self::from_nanos(-self.as_nanos())
+    }
+
+    /// Returns `self + other`, or `None` if it overflows. Being a `const fn`
+    /// it can be used for compile-time timestamps.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// const DEADLINE: Option<Timespec> =
+    ///     Timespec::new(1, 500_000_000).checked_add_duration(&Duration::from_millis(600));
+    /// assert_eq!(DEADLINE, Some(Timespec::new(2, 100_000_000)));
+    /// ```
+    pub const fn checked_add_duration(&self, other: &Duration) -> Option<Timespec> {
         #[inline(always)]
-        // fn checked_add_unsigned(a: i64, b: u64) -> Option<i64> {
+        // const fn checked_add_unsigned(a: i64, b: u64) -> Option<i64> {
         //     a.checked_add_unsigned(b)
         // }
-        fn checked_add_unsigned(a: i64, b: u64) -> Option<i64> {
+        const fn checked_add_unsigned(a: i64, b: u64) -> Option<i64> {
             let b = if b > I64_MAX {
                 return None;
             } else {
@@ -112,24 +1108,40 @@ impl Timespec {
             a.checked_add(b)
         }
 
-        let mut secs = checked_add_unsigned(self.secs(), other.as_secs())?;
+        let mut secs = match checked_add_unsigned(self.secs(), other.as_secs()) {
+            Some(secs) => secs,
+            None => return None,
+        };
 
         // Nano calculations can't overflow because nanos are <1B which fit
         // in a u32.
         let mut nsecs = other.subsec_nanos() + self.nsecs();
         if nsecs >= NSEC_PER_SEC as u32 {
             nsecs -= NSEC_PER_SEC as u32;
-            secs = secs.checked_add(1)?;
+            secs = match secs.checked_add(1) {
+                Some(secs) => secs,
+                None => return None,
+            };
         }
         Some(Timespec::new(secs, nsecs))
     }
 
-    pub fn checked_sub_duration(&self, other: &Duration) -> Option<Timespec> {
+    /// Returns `self - other`, or `None` if it overflows. Being a `const fn`
+    /// it can be used for compile-time timestamps.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// const EARLIER: Option<Timespec> = Timespec::zero().checked_sub_duration(&Duration::from_nanos(1));
+    /// assert_eq!(EARLIER, Some(Timespec::new(-1, 999_999_999)));
+    /// ```
+    pub const fn checked_sub_duration(&self, other: &Duration) -> Option<Timespec> {
         #[inline(always)]
-        // fn checked_sub_unsigned(a: i64, b: u64) -> Option<i64> {
+        // const fn checked_sub_unsigned(a: i64, b: u64) -> Option<i64> {
         //     a.checked_sub_unsigned(b)
         // }
-        fn checked_sub_unsigned(a: i64, b: u64) -> Option<i64> {
+        const fn checked_sub_unsigned(a: i64, b: u64) -> Option<i64> {
             let b = if b > I64_MAX {
                 return None;
             } else {
@@ -138,16 +1150,374 @@ impl Timespec {
             a.checked_sub(b)
         }
 
-        let mut secs = checked_sub_unsigned(self.secs(), other.as_secs())?;
+        let mut secs = match checked_sub_unsigned(self.secs(), other.as_secs()) {
+            Some(secs) => secs,
+            None => return None,
+        };
 
         // Similar to above, nanos can't overflow.
         let mut nsecs = self.nsecs() as i32 - other.subsec_nanos() as i32;
         if nsecs < 0 {
             nsecs += NSEC_PER_SEC as i32;
-            secs = secs.checked_sub(1)?;
+            secs = match secs.checked_sub(1) {
+                Some(secs) => secs,
+                None => return None,
+            };
         }
         Some(Timespec::new(secs, nsecs as u32))
     }
+
+    /// Adds every duration of `durations` to `self` in turn, stopping at
+    /// the first overflow with `None`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let intervals = vec![Duration::from_millis(250); 6];
+    /// assert_eq!(
+    ///     Timespec::new(1, 0).checked_add_durations(intervals),
+    ///     Some(Timespec::new(2, 500_000_000))
+    /// );
+    /// assert_eq!(Timespec::new(1, 0).checked_add_durations([]), Some(Timespec::new(1, 0)));
+    /// assert_eq!(
+    ///     Timespec::zero().checked_add_durations([Duration::MAX, Duration::ZERO]),
+    ///     None
+    /// );
+    /// ```
+    pub fn checked_add_durations<I>(&self, durations: I) -> Option<Timespec>
+    where
+        I: IntoIterator<Item = Duration>,
+    {
+        durations
+            .into_iter()
+            .try_fold(*self, |acc, d| acc.checked_add_duration(&d))
+    }
+
+    /// Multiplies this `Timespec` by `rhs`, returning `None` if the result
+    /// cannot be represented.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(
+    ///     Timespec::new(0, 600_000_000).checked_mul(2),
+    ///     Some(Timespec::new(1, 200_000_000))
+    /// );
+    /// assert_eq!(Timespec::new(i64::MAX, 0).checked_mul(2), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul(&self, rhs: u32) -> Option<Timespec> {
+        Self::from_nanos(self.as_nanos() * rhs as i128)
+    }
+
+    /// Divides this `Timespec` by `rhs`, rounding toward zero to the
+    /// nanosecond. Returns `None` if `rhs` is zero.
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::new(1, 0).checked_div(4), Some(Timespec::new(0, 250_000_000)));
+    /// assert_eq!(Timespec::new(1, 0).checked_div(0), None);
+    /// ```
+    #[inline]
+    pub fn checked_div(&self, rhs: u32) -> Option<Timespec> {
+        if rhs == 0 {
+            None
+        } else {
+            Self::from_nanos(self.as_nanos() / rhs as i128)
+        }
+    }
+
+    /// Same as [`Timespec::checked_add_duration`] but wraps the seconds
+    /// around on overflow.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(i64::MAX, 0);
+    /// assert_eq!(t.wrapping_add_duration(&Duration::from_secs(1)), Timespec::new(i64::MIN, 0));
+    /// assert_eq!(
+    ///     Timespec::new(i64::MIN, 0).wrapping_sub_duration(&Duration::from_secs(1)),
+    ///     t
+    /// );
+    /// ```
+    pub const fn wrapping_add_duration(&self, other: &Duration) -> Timespec {
+        let mut secs = self.secs().wrapping_add(other.as_secs() as i64);
+        let mut nsecs = other.subsec_nanos() + self.nsecs();
+        if nsecs >= NSEC_PER_SEC as u32 {
+            nsecs -= NSEC_PER_SEC as u32;
+            secs = secs.wrapping_add(1);
+        }
+        Timespec::new(secs, nsecs)
+    }
+
+    /// Same as [`Timespec::checked_sub_duration`] but wraps the seconds
+    /// around on overflow.
+    pub const fn wrapping_sub_duration(&self, other: &Duration) -> Timespec {
+        let mut secs = self.secs().wrapping_sub(other.as_secs() as i64);
+        let mut nsecs = self.nsecs() as i32 - other.subsec_nanos() as i32;
+        if nsecs < 0 {
+            nsecs += NSEC_PER_SEC as i32;
+            secs = secs.wrapping_sub(1);
+        }
+        Timespec::new(secs, nsecs as u32)
+    }
+
+    /// Same as [`Timespec::checked_add_duration`] but clamps to
+    /// [`Timespec::MAX`] instead of overflowing.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(i64::MAX, 500_000_000);
+    /// assert_eq!(
+    ///     t.saturating_add_duration(&Duration::from_millis(600)),
+    ///     Timespec::new(i64::MAX, 999_999_999)
+    /// );
+    /// ```
+    pub fn saturating_add_duration(&self, other: &Duration) -> Timespec {
+        self.checked_add_duration(other).unwrap_or(Timespec::MAX)
+    }
+
+    /// Same as [`Timespec::checked_sub_duration`] but clamps to
+    /// [`Timespec::MIN`] instead of overflowing.
+    pub fn saturating_sub_duration(&self, other: &Duration) -> Timespec {
+        self.checked_sub_duration(other).unwrap_or(Timespec::MIN)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Timespec {
+    /// Creates a `Timespec` holding the offset of `t` from the Unix epoch, or
+    /// `None` if it doesn't fit.
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let now = SystemTime::now();
+    /// let t = Timespec::from_system_time(now).unwrap();
+    /// assert_eq!(t.to_system_time(), Some(now));
+    ///
+    /// let t = Timespec::now(ClockId::Realtime).unwrap();
+    /// assert_eq!(Timespec::from_system_time(t.to_system_time().unwrap()), Some(t));
+    /// ```
+    pub fn from_system_time(t: std::time::SystemTime) -> Option<Self> {
+        match t.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => Self::from_duration(d),
+            Err(err) => Self::zero().checked_sub_duration(&err.duration()),
+        }
+    }
+
+    /// Converts this `Timespec`, taken as an offset from the Unix epoch, to a
+    /// [`std::time::SystemTime`], or `None` if it cannot be represented.
+    pub fn to_system_time(&self) -> Option<std::time::SystemTime> {
+        if self.secs() >= 0 {
+            std::time::UNIX_EPOCH.checked_add(Duration::new(self.secs() as u64, self.nsecs()))
+        } else {
+            std::time::UNIX_EPOCH
+                .checked_sub(Duration::from_secs(self.secs().unsigned_abs()))?
+                .checked_add(Duration::from_nanos(self.nsecs() as u64))
+        }
+    }
+
+    /// Converts a reading of the clock backing [`crate::Instant`]
+    /// ([`ClockId::Monotonic`] on Linux) to a [`std::time::Instant`], or
+    /// `None` if it cannot be represented.
+    ///
+    /// The conversion is anchored to a pair of readings of both taken at the
+    /// first call, and assumes they keep moving together: it holds as long as
+    /// std reads the same clock, and is off by the time elapsed between the
+    /// two anchor readings.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let a = Timespec::now(ClockId::Monotonic).unwrap();
+    /// let b = a + Duration::from_millis(5);
+    /// let (ia, ib) = (a.to_std_instant().unwrap(), b.to_std_instant().unwrap());
+    /// assert!(ia < ib);
+    /// assert_eq!(ib - ia, Duration::from_millis(5));
+    /// ```
+    pub fn to_std_instant(&self) -> Option<std::time::Instant> {
+        static ANCHOR: std::sync::Mutex<Option<(Timespec, std::time::Instant)>> =
+            std::sync::Mutex::new(None);
+
+        let mut anchor = ANCHOR.lock().unwrap_or_else(|err| err.into_inner());
+        let (t, instant) = match *anchor {
+            Some(anchor) => anchor,
+            None => *anchor.insert((Self::now(INSTANT_CLOCKID).ok()?, std::time::Instant::now())),
+        };
+        drop(anchor);
+
+        match self.sub_timespec(&t) {
+            Ok(d) => instant.checked_add(d),
+            Err(d) => instant.checked_sub(d),
+        }
+    }
+
+    /// Reads the clock `clockid` `count + 1` times in a row and returns the
+    /// `count` deltas between successive readings, useful to characterize
+    /// the cost and granularity of a clock. A delta is zero if the clock went
+    /// backwards.
+    ///
+    /// ```
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let deltas = Timespec::sample_deltas(ClockId::Monotonic, 100).unwrap();
+    /// assert_eq!(deltas.len(), 100);
+    /// assert!(Timespec::sample_deltas(ClockId::Monotonic, 0).unwrap().is_empty());
+    /// ```
+    pub fn sample_deltas(clockid: ClockId, count: usize) -> Result<Vec<Duration>, Errno> {
+        let mut deltas = Vec::with_capacity(count);
+        let mut prev = Self::now(clockid)?;
+        for _ in 0..count {
+            let curr = Self::now(clockid)?;
+            deltas.push(curr.sub_timespec(&prev).unwrap_or_default());
+            prev = curr;
+        }
+        Ok(deltas)
+    }
+}
+
+/// Converts a `libc::timespec`, carrying a negative or oversized `tv_nsec` into the seconds and
+/// saturating to [`Timespec::MIN`] or [`Timespec::MAX`] if they overflow.
+///
+/// ```
+/// use unix_clock::raw::Timespec;
+///
+/// let t = Timespec::from(libc::timespec { tv_sec: 1, tv_nsec: -1 });
+/// assert_eq!(t, Timespec::new(0, 999_999_999));
+/// assert_eq!(libc::timespec::try_from(t).unwrap().tv_nsec, 999_999_999);
+///
+/// if let Ok(tv_sec) = libc::time_t::try_from(i64::MAX) {
+///     let t = Timespec::from(libc::timespec { tv_sec, tv_nsec: 1_000_000_000 });
+///     assert_eq!(t, Timespec::MAX);
+/// }
+/// ```
+#[cfg(any(feature = "libc", not(target_os = "linux")))]
+impl From<libc::timespec> for Timespec {
+    #[allow(clippy::unnecessary_cast)]
+    fn from(value: libc::timespec) -> Self {
+        Self::saturating_from_signed(value.tv_sec as i64, value.tv_nsec as i64)
+    }
+}
+
+/// Converts to a `libc::timespec`, failing with `EOVERFLOW` if the seconds don't fit in the
+/// target's `time_t`.
+#[cfg(any(feature = "libc", not(target_os = "linux")))]
+impl TryFrom<Timespec> for libc::timespec {
+    type Error = Errno;
+
+    #[allow(clippy::useless_conversion)]
+    fn try_from(value: Timespec) -> Result<Self, Self::Error> {
+        Ok(libc::timespec {
+            tv_sec: value.secs().try_into().map_err(|_| Errno::EOVERFLOW)?,
+            tv_nsec: value.nsecs() as _,
+        })
+    }
+}
+
+/// Converts a [`Duration`] since the clock's origin. Durations with more
+/// than `i64::MAX` seconds (about 292 billion years) are clamped to
+/// [`Timespec::MAX`] instead of panicking, use [`Timespec::from_duration`] to
+/// detect them.
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::raw::Timespec;
+///
+/// assert_eq!(Timespec::from(Duration::new(5, 42)), Timespec::new(5, 42));
+/// assert_eq!(Timespec::from(Duration::new(i64::MAX as u64, 7)), Timespec::new(i64::MAX, 7));
+/// assert_eq!(Timespec::from(Duration::new(i64::MAX as u64 + 1, 0)), Timespec::MAX);
+/// assert_eq!(Timespec::from(Duration::MAX), Timespec::MAX);
+/// ```
+impl From<Duration> for Timespec {
+    #[inline]
+    fn from(value: Duration) -> Self {
+        match Self::from_duration(value) {
+            Some(t) => t,
+            None => Self::MAX,
+        }
+    }
+}
+
+impl core::ops::Add<Duration> for Timespec {
+    type Output = Timespec;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure. See [`Timespec::checked_add_duration`] for a version without
+    /// panic.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(1, 600_000_000) + Duration::new(1, 500_000_000);
+    /// assert_eq!(t, Timespec::new(3, 100_000_000));
+    /// ```
+    fn add(self, other: Duration) -> Timespec {
+        self.checked_add_duration(&other)
+            .expect("overflow when adding duration to timespec")
+    }
+}
+
+impl core::ops::AddAssign<Duration> for Timespec {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub<Duration> for Timespec {
+    type Output = Timespec;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure. See [`Timespec::checked_sub_duration`] for a version without
+    /// panic.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(3, 100_000_000) - Duration::new(1, 500_000_000);
+    /// assert_eq!(t, Timespec::new(1, 600_000_000));
+    /// ```
+    fn sub(self, other: Duration) -> Timespec {
+        self.checked_sub_duration(&other)
+            .expect("overflow when subtracting duration from timespec")
+    }
+}
+
+impl core::ops::SubAssign<Duration> for Timespec {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Sub<Timespec> for Timespec {
+    type Output = SignedDuration;
+
+    /// Same as [`Timespec::signed_sub`], negative if `other` is later than `self`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{SignedDuration, Timespec};
+    ///
+    /// let (a, b) = (Timespec::new(1, 0), Timespec::new(2, 500_000_000));
+    /// assert_eq!(b - a, SignedDuration::from(Duration::from_millis(1_500)));
+    /// assert_eq!(a - b, -SignedDuration::from(Duration::from_millis(1_500)));
+    /// ```
+    fn sub(self, other: Timespec) -> SignedDuration {
+        self.signed_sub(&other)
+    }
 }
 
 impl Default for Timespec {
@@ -166,6 +1536,112 @@ impl fmt::Debug for Timespec {
     }
 }
 
+/// Formats the `Timespec` as `secs.nanoseconds`, truncating the fractional part to the formatter
+/// precision if given.
+///
+/// ```
+/// use unix_clock::raw::Timespec;
+///
+/// assert_eq!(Timespec::new(5, 250).to_string(), "5.000000250");
+/// assert_eq!(format!("{:.3}", Timespec::new(5, 123_456_789)), "5.123");
+/// assert_eq!(format!("{:.0}", Timespec::new(5, 123_456_789)), "5");
+/// assert_eq!(Timespec::new(-1, 500_000_000).to_string(), "-0.500000000");
+/// ```
+impl fmt::Display for Timespec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.as_nanos();
+        if nanos < 0 {
+            f.write_str("-")?;
+        }
+        let nanos = nanos.unsigned_abs();
+        write!(f, "{}", nanos / NSEC_PER_SEC as u128)?;
+
+        let precision = f.precision().unwrap_or(9).min(9);
+        if precision > 0 {
+            let frac = (nanos % NSEC_PER_SEC as u128) / 10u128.pow(9 - precision as u32);
+            write!(f, ".{:0width$}", frac, width = precision)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when parsing a [`Timespec`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimespecError(());
+
+impl fmt::Display for ParseTimespecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid timespec literal")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTimespecError {}
+
+/// Parses a `Timespec` from `secs[.fraction]` with an optional sign and up
+/// to 9 fractional digits, the format written by its `Display` impl.
+///
+/// ```
+/// use unix_clock::raw::Timespec;
+///
+/// assert_eq!("1".parse(), Ok(Timespec::new(1, 0)));
+/// assert_eq!("1.000000001".parse(), Ok(Timespec::new(1, 1)));
+/// assert_eq!("-0.5".parse(), Ok(Timespec::new(-1, 500_000_000)));
+/// assert!("1.2.3".parse::<Timespec>().is_err());
+/// assert!("1.0000000001".parse::<Timespec>().is_err());
+///
+/// let t = Timespec::new(-42, 123);
+/// assert_eq!(t.to_string().parse(), Ok(t));
+/// ```
+impl core::str::FromStr for Timespec {
+    type Err = ParseTimespecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn digits(s: &str) -> Result<u128, ParseTimespecError> {
+            if s.is_empty() {
+                return Err(ParseTimespecError(()));
+            }
+            s.bytes().try_fold(0u128, |acc, b| {
+                if b.is_ascii_digit() {
+                    acc.checked_mul(10)
+                        .and_then(|acc| acc.checked_add((b - b'0') as u128))
+                        .ok_or(ParseTimespecError(()))
+                } else {
+                    Err(ParseTimespecError(()))
+                }
+            })
+        }
+
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (secs, frac) = match s.split_once('.') {
+            Some((secs, frac)) => (secs, Some(frac)),
+            None => (s, None),
+        };
+
+        let secs = digits(secs)?;
+        let nsecs = match frac {
+            Some(frac) if frac.len() <= 9 => digits(frac)? * 10u128.pow(9 - frac.len() as u32),
+            Some(_) => return Err(ParseTimespecError(())),
+            None => 0,
+        };
+
+        let nanos = secs
+            .checked_mul(NSEC_PER_SEC as u128)
+            .and_then(|n| n.checked_add(nsecs))
+            .and_then(|n| i128::try_from(n).ok())
+            .ok_or(ParseTimespecError(()))?;
+        Self::from_nanos(if negative { -nanos } else { nanos }).ok_or(ParseTimespecError(()))
+    }
+}
+
+/// Compares the raw seconds and nanoseconds, consistently with [`Ord`] and
+/// [`Hash`](core::hash::Hash): a denormalized value is never equal to its
+/// normalized counterpart. Normalize first, or use
+/// [`Timespec::cmp_normalized`], to compare instants.
 impl PartialEq for Timespec {
     fn eq(&self, other: &Self) -> bool {
         self.secs() == other.secs() && self.nsecs() == other.nsecs()
@@ -175,17 +1651,61 @@ impl PartialEq for Timespec {
 impl Eq for Timespec {}
 
 impl PartialOrd for Timespec {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.secs().partial_cmp(&other.secs()) {
-            Some(core::cmp::Ordering::Equal) => (),
-            ord => return ord,
-        }
-        self.nsecs().partial_cmp(&other.nsecs())
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
+/// Orders by seconds then nanoseconds, ignoring the padding, so `min`, `max`
+/// and `clamp` come for free. Denormalized values (see
+/// [`Timespec::is_normalized`]) are not ordered chronologically, use
+/// [`Timespec::cmp_normalized`] for them.
+///
+/// ```
+/// use unix_clock::raw::Timespec;
+///
+/// let lo = Timespec::new(1, 0);
+/// let hi = Timespec::new(2, 0);
+/// assert_eq!(Timespec::new(0, 999_999_999).clamp(lo, hi), lo);
+/// assert_eq!(Timespec::new(2, 1).clamp(lo, hi), hi);
+/// assert_eq!(Timespec::new(1, 5).clamp(lo, hi), Timespec::new(1, 5));
+/// assert_eq!(lo.clamp(lo, lo), lo);
+/// assert_eq!(Timespec::MIN.max(lo), lo);
+/// assert_eq!(Timespec::MAX.min(hi), hi);
+/// ```
+///
+/// For normalized values the order is chronological, negative times
+/// included: `-0.5s` is stored as `(-1, 500_000_000)`, since the nanoseconds
+/// are unsigned, so comparing the seconds first is enough.
+///
+/// ```
+/// use unix_clock::raw::Timespec;
+///
+/// let sorted = [
+///     Timespec::MIN,
+///     Timespec::new(-1, 0),
+///     Timespec::checked_from_signed(0, -500_000_000).unwrap(),
+///     Timespec::checked_from_signed(0, -1).unwrap(),
+///     Timespec::zero(),
+///     Timespec::new(0, 1),
+///     Timespec::new(1, 0),
+///     Timespec::MAX,
+/// ];
+/// for w in sorted.windows(2) {
+///     assert!(w[0] < w[1]);
+///     assert!(w[0].as_nanos() < w[1].as_nanos());
+/// }
+/// ```
+///
+/// Like in std, `clamp` panics if `min > max`:
+///
+/// ```should_panic
+/// use unix_clock::raw::Timespec;
+///
+/// Timespec::zero().clamp(Timespec::new(2, 0), Timespec::new(1, 0));
+/// ```
 impl Ord for Timespec {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         match self.secs().cmp(&other.secs()) {
             core::cmp::Ordering::Equal => (),
             ord => return ord,
@@ -194,8 +1714,32 @@ impl Ord for Timespec {
     }
 }
 
+/// Hashes the raw fields, so that `a == b` implies `hash(a) == hash(b)`
+/// for denormalized values too.
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+/// use unix_clock::raw::Timespec;
+///
+/// fn hash(t: &Timespec) -> u64 {
+///     let mut h = DefaultHasher::new();
+///     t.hash(&mut h);
+///     h.finish()
+/// }
+///
+/// let a = Timespec::new(1, 1_500_000_000);
+/// let b = Timespec::new(1, 1_500_000_000);
+/// let c = Timespec::new(2, 500_000_000);
+/// assert!(a == b && hash(&a) == hash(&b));
+/// assert_ne!(a, c);
+///
+/// let mut n = a;
+/// n.normalize();
+/// assert!(n == c && hash(&n) == hash(&c));
+/// ```
 impl core::hash::Hash for Timespec {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.secs().hash(state);
         self.nsecs().hash(state);
     }
@@ -0,0 +1,42 @@
+use nix::sys::time::TimeSpec;
+
+use super::Timespec;
+use crate::Errno;
+
+/// Converts from nix's `TimeSpec`, normalizing its signed nanoseconds into
+/// the seconds and saturating to [`Timespec::MIN`] or [`Timespec::MAX`] if
+/// they overflow.
+///
+/// ```
+/// use nix::sys::time::TimeSpec;
+/// use unix_clock::raw::Timespec;
+///
+/// for t in [Timespec::new(1, 500_000_000), Timespec::new(-2, 999_999_999)] {
+///     assert_eq!(Timespec::from(TimeSpec::try_from(t).unwrap()), t);
+/// }
+/// assert_eq!(Timespec::from(TimeSpec::new(0, -1)), Timespec::new(-1, 999_999_999));
+///
+/// if let Ok(secs) = i64::MIN.try_into() {
+///     assert_eq!(Timespec::from(TimeSpec::new(secs, -1)), Timespec::MIN);
+/// }
+/// ```
+impl From<TimeSpec> for Timespec {
+    #[allow(clippy::unnecessary_cast)]
+    fn from(value: TimeSpec) -> Self {
+        Self::saturating_from_signed(value.tv_sec() as i64, value.tv_nsec() as i64)
+    }
+}
+
+/// Converts to nix's `TimeSpec`, failing with `EOVERFLOW` if the seconds don't
+/// fit in the target's `time_t`.
+impl TryFrom<Timespec> for TimeSpec {
+    type Error = Errno;
+
+    #[allow(clippy::useless_conversion)]
+    fn try_from(value: Timespec) -> Result<Self, Self::Error> {
+        Ok(TimeSpec::new(
+            value.secs().try_into().map_err(|_| Errno::EOVERFLOW)?,
+            value.nsecs() as _,
+        ))
+    }
+}
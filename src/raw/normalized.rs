@@ -0,0 +1,166 @@
+use core::{fmt, time::Duration};
+
+use super::Timespec;
+use crate::Errno;
+
+/// A [`Timespec`] whose nanoseconds are always less than a second, so its
+/// [`Eq`], [`Ord`] and [`Hash`](core::hash::Hash) impls follow the instant it
+/// represents.
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+/// use unix_clock::raw::{NormalizedTimespec, Timespec};
+///
+/// fn hash(t: &NormalizedTimespec) -> u64 {
+///     let mut h = DefaultHasher::new();
+///     t.hash(&mut h);
+///     h.finish()
+/// }
+///
+/// let a = NormalizedTimespec::new(1, 1_500_000_000);
+/// let b = NormalizedTimespec::try_from(Timespec::new(2, 500_000_000)).unwrap();
+/// assert!(a == b && hash(&a) == hash(&b));
+/// assert_eq!(Timespec::from(a), Timespec::new(2, 500_000_000));
+/// assert!(NormalizedTimespec::try_from(Timespec::new(1, 1_500_000_000)).is_err());
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NormalizedTimespec(Timespec);
+
+impl NormalizedTimespec {
+    /// Creates a new `NormalizedTimespec` carrying the nanoseconds exceeding
+    /// a second into `secs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting seconds overflow an `i64`.
+    #[inline]
+    pub const fn new(secs: i64, nsecs: u64) -> Self {
+        Self(Timespec::from_parts(secs, nsecs))
+    }
+
+    /// Returns the current time of the clock `clockid`.
+    #[inline]
+    pub fn now(clockid: super::ClockId) -> Result<Self, Errno> {
+        let mut t = Timespec::now(clockid)?;
+        t.normalize();
+        Ok(Self(t))
+    }
+
+    /// Returns the wrapped [`Timespec`].
+    #[inline]
+    pub const fn as_timespec(&self) -> Timespec {
+        self.0
+    }
+
+    /// Returns the amount of time elapsed from `other` to `self`, see
+    /// [`Timespec::sub_timespec`].
+    #[inline]
+    pub fn sub_timespec(&self, other: &NormalizedTimespec) -> Result<Duration, Duration> {
+        self.0.sub_timespec(&other.0)
+    }
+
+    /// See [`Timespec::checked_add_duration`].
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::NormalizedTimespec;
+    ///
+    /// let t = NormalizedTimespec::new(1, 999_999_999);
+    /// assert_eq!(
+    ///     t.checked_add_duration(&Duration::from_nanos(1)),
+    ///     Some(NormalizedTimespec::new(2, 0))
+    /// );
+    /// assert_eq!(
+    ///     t.checked_sub_duration(&Duration::from_secs(2)),
+    ///     Some(NormalizedTimespec::new(-1, 999_999_999))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn checked_add_duration(&self, other: &Duration) -> Option<Self> {
+        match self.0.checked_add_duration(other) {
+            Some(t) => Some(Self(t)),
+            None => None,
+        }
+    }
+
+    /// See [`Timespec::checked_sub_duration`].
+    #[inline]
+    pub const fn checked_sub_duration(&self, other: &Duration) -> Option<Self> {
+        match self.0.checked_sub_duration(other) {
+            Some(t) => Some(Self(t)),
+            None => None,
+        }
+    }
+
+    /// See [`Timespec::saturating_add_duration`].
+    #[inline]
+    pub fn saturating_add_duration(&self, other: &Duration) -> Self {
+        Self(self.0.saturating_add_duration(other))
+    }
+
+    /// See [`Timespec::saturating_sub_duration`].
+    #[inline]
+    pub fn saturating_sub_duration(&self, other: &Duration) -> Self {
+        Self(self.0.saturating_sub_duration(other))
+    }
+}
+
+impl From<NormalizedTimespec> for Timespec {
+    #[inline]
+    fn from(value: NormalizedTimespec) -> Self {
+        value.0
+    }
+}
+
+/// Fails with `EINVAL` if the nanoseconds are not less than a second, see
+/// [`Timespec::normalize`].
+impl TryFrom<Timespec> for NormalizedTimespec {
+    type Error = Errno;
+
+    #[inline]
+    fn try_from(value: Timespec) -> Result<Self, Self::Error> {
+        if value.is_normalized() {
+            Ok(Self(value))
+        } else {
+            Err(Errno::EINVAL)
+        }
+    }
+}
+
+impl core::ops::Add<Duration> for NormalizedTimespec {
+    type Output = NormalizedTimespec;
+
+    /// # Panics
+    ///
+    /// Panics on overflow, see [`NormalizedTimespec::checked_add_duration`].
+    fn add(self, rhs: Duration) -> Self {
+        self.checked_add_duration(&rhs)
+            .expect("overflow when adding duration to timespec")
+    }
+}
+
+impl core::ops::Sub<Duration> for NormalizedTimespec {
+    type Output = NormalizedTimespec;
+
+    /// # Panics
+    ///
+    /// Panics on overflow, see [`NormalizedTimespec::checked_sub_duration`].
+    fn sub(self, rhs: Duration) -> Self {
+        self.checked_sub_duration(&rhs)
+            .expect("overflow when subtracting duration from timespec")
+    }
+}
+
+impl fmt::Debug for NormalizedTimespec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for NormalizedTimespec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
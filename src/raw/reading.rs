@@ -0,0 +1,177 @@
+use core::{fmt, marker::PhantomData, time::Duration};
+
+use super::{Clock, SignedDuration, Timespec};
+use crate::Errno;
+
+/// A [`Timespec`] tagged with the [`Clock`] it has been read from, so that
+/// readings of different clocks can't be mixed up in arithmetic.
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::raw::{MonotonicClock, Reading, SignedDuration};
+///
+/// let start = Reading::<MonotonicClock>::now().unwrap();
+/// let end = Reading::<MonotonicClock>::now().unwrap();
+/// assert!(!(end - start).is_negative());
+/// assert_eq!(
+///     (start + Duration::from_secs(1)) - start,
+///     SignedDuration::from(Duration::from_secs(1))
+/// );
+/// ```
+///
+/// Subtracting readings of different clocks doesn't compile:
+///
+/// ```compile_fail
+/// use unix_clock::raw::{MonotonicClock, Reading, RealtimeClock};
+///
+/// let mono = Reading::<MonotonicClock>::now().unwrap();
+/// let real = Reading::<RealtimeClock>::now().unwrap();
+/// let _ = mono - real;
+/// ```
+#[repr(transparent)]
+pub struct Reading<C> {
+    t: Timespec,
+    clock: PhantomData<fn() -> C>,
+}
+
+impl<C: Clock> Reading<C> {
+    /// Returns the current time of the clock `C`.
+    #[inline]
+    pub fn now() -> Result<Self, Errno> {
+        C::now().map(Self::new)
+    }
+
+    /// Returns the amount of time elapsed since this reading, read from the
+    /// same clock `C`, or zero duration if the clock went backwards.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{MonotonicClock, Reading};
+    ///
+    /// let t = Reading::<MonotonicClock>::now().unwrap();
+    /// assert!(t.age().unwrap() < Duration::from_secs(1));
+    /// ```
+    #[inline]
+    pub fn age(&self) -> Result<Duration, Errno> {
+        Ok(C::now()?.sub_timespec(&self.t).unwrap_or_default())
+    }
+}
+
+impl<C> Reading<C> {
+    /// Wraps `t`, trusting it to be a reading of the clock `C`.
+    #[inline]
+    pub const fn new(t: Timespec) -> Self {
+        Self {
+            t,
+            clock: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped [`Timespec`].
+    #[inline]
+    pub const fn as_timespec(&self) -> Timespec {
+        self.t
+    }
+
+    /// See [`Timespec::checked_add_duration`].
+    #[inline]
+    pub const fn checked_add_duration(&self, other: &Duration) -> Option<Self> {
+        match self.t.checked_add_duration(other) {
+            Some(t) => Some(Self::new(t)),
+            None => None,
+        }
+    }
+
+    /// See [`Timespec::checked_sub_duration`].
+    #[inline]
+    pub const fn checked_sub_duration(&self, other: &Duration) -> Option<Self> {
+        match self.t.checked_sub_duration(other) {
+            Some(t) => Some(Self::new(t)),
+            None => None,
+        }
+    }
+}
+
+impl<C> From<Reading<C>> for Timespec {
+    #[inline]
+    fn from(value: Reading<C>) -> Self {
+        value.t
+    }
+}
+
+impl<C> Clone for Reading<C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Reading<C> {}
+
+impl<C> PartialEq for Reading<C> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t
+    }
+}
+
+impl<C> Eq for Reading<C> {}
+
+impl<C> PartialOrd for Reading<C> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for Reading<C> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.t.cmp(&other.t)
+    }
+}
+
+impl<C> core::hash::Hash for Reading<C> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.t.hash(state)
+    }
+}
+
+impl<C> fmt::Debug for Reading<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.t, f)
+    }
+}
+
+impl<C> core::ops::Add<Duration> for Reading<C> {
+    type Output = Reading<C>;
+
+    /// # Panics
+    ///
+    /// Panics on overflow, see [`Reading::checked_add_duration`].
+    fn add(self, rhs: Duration) -> Self {
+        Self::new(self.t + rhs)
+    }
+}
+
+impl<C> core::ops::Sub<Duration> for Reading<C> {
+    type Output = Reading<C>;
+
+    /// # Panics
+    ///
+    /// Panics on overflow, see [`Reading::checked_sub_duration`].
+    fn sub(self, rhs: Duration) -> Self {
+        Self::new(self.t - rhs)
+    }
+}
+
+impl<C> core::ops::Sub for Reading<C> {
+    type Output = SignedDuration;
+
+    /// Same as [`Timespec::signed_sub`], only between readings of the same
+    /// clock.
+    fn sub(self, other: Self) -> SignedDuration {
+        self.t.signed_sub(&other.t)
+    }
+}
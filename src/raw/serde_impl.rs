@@ -0,0 +1,221 @@
+use core::fmt;
+
+use serde::{
+    de::{self, MapAccess, SeqAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{ClockId, Timespec, NSEC_PER_SEC};
+
+const FIELDS: &[&str] = &["secs", "nsecs"];
+
+impl Serialize for Timespec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Timespec", 2)?;
+        state.serialize_field("secs", &self.secs())?;
+        state.serialize_field("nsecs", &self.nsecs())?;
+        state.end()
+    }
+}
+
+enum Field {
+    Secs,
+    Nsecs,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("`secs` or `nsecs`")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Field, E> {
+                match value {
+                    0 => Ok(Field::Secs),
+                    1 => Ok(Field::Nsecs),
+                    _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+                }
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "secs" => Ok(Field::Secs),
+                    "nsecs" => Ok(Field::Nsecs),
+                    _ => Err(E::unknown_field(value, FIELDS)),
+                }
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Field, E> {
+                match value {
+                    b"secs" => Ok(Field::Secs),
+                    b"nsecs" => Ok(Field::Nsecs),
+                    _ => Err(E::invalid_value(Unexpected::Other("unknown field"), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+fn check_nsecs<E: de::Error>(secs: i64, nsecs: u32) -> Result<Timespec, E> {
+    if nsecs as u64 >= NSEC_PER_SEC {
+        Err(E::invalid_value(
+            Unexpected::Unsigned(nsecs as u64),
+            &"nanoseconds less than 1_000_000_000",
+        ))
+    } else {
+        Ok(Timespec::new(secs, nsecs))
+    }
+}
+
+impl<'de> Deserialize<'de> for Timespec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimespecVisitor;
+
+        impl<'de> Visitor<'de> for TimespecVisitor {
+            type Value = Timespec;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Timespec")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Timespec, A::Error> {
+                let secs = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let nsecs = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                check_nsecs(secs, nsecs)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Timespec, A::Error> {
+                let mut secs = None;
+                let mut nsecs = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Secs => {
+                            if secs.is_some() {
+                                return Err(de::Error::duplicate_field("secs"));
+                            }
+                            secs = Some(map.next_value()?);
+                        }
+                        Field::Nsecs => {
+                            if nsecs.is_some() {
+                                return Err(de::Error::duplicate_field("nsecs"));
+                            }
+                            nsecs = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let secs = secs.ok_or_else(|| de::Error::missing_field("secs"))?;
+                let nsecs = nsecs.ok_or_else(|| de::Error::missing_field("nsecs"))?;
+                check_nsecs(secs, nsecs)
+            }
+        }
+
+        deserializer.deserialize_struct("Timespec", FIELDS, TimespecVisitor)
+    }
+}
+
+impl Serialize for ClockId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for ClockId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ClockIdVisitor;
+
+        impl<'de> Visitor<'de> for ClockIdVisitor {
+            type Value = ClockId;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a clock name")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<ClockId, E> {
+                ClockId::ALL
+                    .iter()
+                    .find(|id| id.name().eq_ignore_ascii_case(value))
+                    .copied()
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(ClockIdVisitor)
+    }
+}
+
+/// Serializes a [`Timespec`] as its total number of nanoseconds (see
+/// [`Timespec::as_nanos`]), to be used as
+/// `#[serde(with = "unix_clock::raw::timespec_nanos")]`.
+///
+/// Deserialization accepts both integers and strings holding an integer.
+///
+/// Counts outside the `i64`/`u64` range need a format handing out `i128`s
+/// when asked for any value: `serde_json` without its `arbitrary_precision`
+/// feature turns them into lossy `f64`s, which are rejected rather than
+/// rounded, so such counts only round-trip there when written as strings.
+pub mod timespec_nanos {
+    use core::fmt;
+
+    use serde::{
+        de::{self, Unexpected, Visitor},
+        Deserializer, Serializer,
+    };
+
+    use super::Timespec;
+
+    pub fn serialize<S: Serializer>(t: &Timespec, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i128(t.as_nanos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timespec, D::Error> {
+        struct NanosVisitor;
+
+        impl<'de> Visitor<'de> for NanosVisitor {
+            type Value = Timespec;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a number of nanoseconds")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Timespec, E> {
+                self.visit_i128(value as i128)
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Timespec, E> {
+                self.visit_i128(value as i128)
+            }
+
+            fn visit_i128<E: de::Error>(self, value: i128) -> Result<Timespec, E> {
+                Timespec::from_nanos(value)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Other("i128"), &self))
+            }
+
+            fn visit_u128<E: de::Error>(self, value: u128) -> Result<Timespec, E> {
+                i128::try_from(value)
+                    .map_err(|_| E::invalid_value(Unexpected::Other("u128"), &self))
+                    .and_then(|value| self.visit_i128(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Timespec, E> {
+                value
+                    .parse::<i128>()
+                    .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+                    .and_then(|value| self.visit_i128(value))
+            }
+        }
+
+        deserializer.deserialize_any(NanosVisitor)
+    }
+}
@@ -0,0 +1,203 @@
+use core::{cmp::Ordering, fmt, time::Duration};
+
+use super::{ClockId, Timespec};
+use crate::Errno;
+
+/// A [`Duration`] with a sign, the difference between two [`Timespec`]s.
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::raw::{SignedDuration, Timespec};
+///
+/// let a = Timespec::new(1, 200_000_000);
+/// let b = Timespec::new(2, 100_000_000);
+///
+/// assert_eq!(b.signed_sub(&a), SignedDuration::from(b.sub_timespec(&a).unwrap()));
+/// assert_eq!(a.signed_sub(&b), -SignedDuration::from(a.sub_timespec(&b).unwrap_err()));
+/// assert!(a.signed_sub(&b).is_negative());
+/// assert_eq!(a.signed_sub(&b).unsigned_abs(), Duration::from_millis(900));
+/// assert_eq!(a.signed_sub(&b).as_duration(), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SignedDuration {
+    negative: bool,
+    magnitude: Duration,
+}
+
+impl SignedDuration {
+    /// A zero length signed duration.
+    pub const ZERO: Self = Self::new(false, Duration::ZERO);
+
+    /// Creates a new `SignedDuration` from its sign and magnitude. A zero
+    /// magnitude is never negative.
+    #[inline]
+    pub const fn new(negative: bool, magnitude: Duration) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Returns `true` if the duration is less than zero.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the absolute value of the duration.
+    #[inline]
+    pub const fn abs(&self) -> Self {
+        Self::new(false, self.magnitude)
+    }
+
+    /// Returns the absolute value of the duration as a [`Duration`].
+    #[inline]
+    pub const fn unsigned_abs(&self) -> Duration {
+        self.magnitude
+    }
+
+    /// Returns the duration as a [`Duration`], or `None` if it is negative.
+    #[inline]
+    pub const fn as_duration(&self) -> Option<Duration> {
+        if self.negative {
+            None
+        } else {
+            Some(self.magnitude)
+        }
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    #[inline]
+    fn from(value: Duration) -> Self {
+        Self::new(false, value)
+    }
+}
+
+impl core::ops::Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    #[inline]
+    fn neg(self) -> SignedDuration {
+        Self::new(!self.negative, self.magnitude)
+    }
+}
+
+impl PartialOrd for SignedDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignedDuration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl fmt::Debug for SignedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        fmt::Debug::fmt(&self.magnitude, f)
+    }
+}
+
+impl Timespec {
+    /// Returns the signed amount of time elapsed from `other` to `self`,
+    /// negative if `other` is later than `self`.
+    #[inline]
+    pub fn signed_sub(&self, other: &Timespec) -> SignedDuration {
+        match self.sub_timespec(other) {
+            Ok(d) => SignedDuration::new(false, d),
+            Err(d) => SignedDuration::new(true, d),
+        }
+    }
+
+    /// Returns `self` moved by `delta`, or `None` if it overflows.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{SignedDuration, Timespec};
+    ///
+    /// let back = -SignedDuration::from(Duration::from_millis(1_500));
+    /// assert_eq!(
+    ///     Timespec::new(1, 0).checked_add_signed(&back),
+    ///     Some(Timespec::new(-1, 500_000_000))
+    /// );
+    /// assert_eq!(Timespec::MIN.checked_add_signed(&back), None);
+    /// ```
+    #[inline]
+    pub const fn checked_add_signed(&self, delta: &SignedDuration) -> Option<Timespec> {
+        if delta.negative {
+            self.checked_sub_duration(&delta.magnitude)
+        } else {
+            self.checked_add_duration(&delta.magnitude)
+        }
+    }
+}
+
+/// Steps the [`ClockId::Realtime`] clock by `delta`, failing with
+/// `EOVERFLOW` instead of wrapping if the new time isn't representable.
+/// Requires the `CAP_SYS_TIME` capability (or being root).
+///
+/// The clock keeps running between reading and setting it, so the step is
+/// off by the time spent in between.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use unix_clock::raw::{step_realtime, SignedDuration};
+///
+/// let delta = SignedDuration::from(Duration::from_secs(1));
+/// step_realtime(delta).unwrap();
+/// step_realtime(-delta).unwrap();
+/// ```
+pub fn step_realtime(delta: SignedDuration) -> Result<(), Errno> {
+    Ok(Timespec::now(ClockId::Realtime)?
+        .checked_add_signed(&delta)
+        .ok_or(Errno::EOVERFLOW)?
+        .set_clock_id(ClockId::Realtime)?)
+}
+
+/// Returns `true` if the [`ClockId::Realtime`] clock has been stepped by
+/// more than `threshold` between the readings `before` and `after`, i.e. if
+/// the time elapsed on it differs from the time elapsed on
+/// [`ClockId::Monotonic`] between `monotonic_before` and `monotonic_after`,
+/// which is not affected by steps.
+///
+/// Each pair of readings should be taken back to back (see
+/// [`Timespec::now_pair`]), and `threshold` should be larger than the
+/// slewing applied by NTP over the interval.
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::raw::{detect_step, Timespec};
+///
+/// let (mono_before, mono_after) = (Timespec::new(100, 0), Timespec::new(110, 0));
+/// let before = Timespec::new(1_700_000_000, 0);
+/// let threshold = Duration::from_millis(100);
+///
+/// let after = Timespec::new(1_700_000_010, 50_000_000);
+/// assert!(!detect_step(&before, &after, &mono_before, &mono_after, threshold));
+/// let after = Timespec::new(1_700_000_015, 0);
+/// assert!(detect_step(&before, &after, &mono_before, &mono_after, threshold));
+/// let after = Timespec::new(1_699_999_990, 0);
+/// assert!(detect_step(&before, &after, &mono_before, &mono_after, threshold));
+/// ```
+pub fn detect_step(
+    before: &Timespec,
+    after: &Timespec,
+    monotonic_before: &Timespec,
+    monotonic_after: &Timespec,
+    threshold: Duration,
+) -> bool {
+    let realtime = after.as_nanos() - before.as_nanos();
+    let monotonic = monotonic_after.as_nanos() - monotonic_before.as_nanos();
+    (realtime - monotonic).unsigned_abs() > threshold.as_nanos()
+}
@@ -0,0 +1,54 @@
+use super::{ClockId, Timespec};
+use crate::Errno;
+
+/// The readings of the [`ClockId::Monotonic`], [`ClockId::Realtime`] and
+/// [`ClockId::Boottime`] clocks taken in quick succession, used to correlate
+/// timestamps between them.
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::raw::ClockSnapshot;
+///
+/// let a = ClockSnapshot::capture().unwrap();
+/// let b = ClockSnapshot::capture().unwrap();
+///
+/// // Both snapshots agree on the wall-clock time of the same monotonic reading
+/// // within the reading jitter, unless the realtime clock got stepped.
+/// let ra = a.realtime_for_monotonic(&b.monotonic);
+/// assert!(ra.approx_eq(&b.realtime, Duration::from_millis(100)));
+/// assert!(b.boottime >= b.monotonic);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockSnapshot {
+    /// The reading of [`ClockId::Monotonic`].
+    pub monotonic: Timespec,
+    /// The reading of [`ClockId::Realtime`].
+    pub realtime: Timespec,
+    /// The reading of [`ClockId::Boottime`].
+    pub boottime: Timespec,
+}
+
+impl ClockSnapshot {
+    /// Reads the three clocks one right after the other. The readings are not
+    /// atomic, they're apart by the cost of one clock read.
+    pub fn capture() -> Result<Self, Errno> {
+        Ok(Self {
+            monotonic: Timespec::now(ClockId::Monotonic)?,
+            realtime: Timespec::now(ClockId::Realtime)?,
+            boottime: Timespec::now(ClockId::Boottime)?,
+        })
+    }
+
+    /// Maps the [`ClockId::Monotonic`] reading `m` to wall-clock time, using
+    /// the offset between the clocks at the time of the snapshot. Steps and
+    /// slews of the realtime clock after the capture are not accounted for.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result cannot be represented by a [`Timespec`].
+    pub fn realtime_for_monotonic(&self, m: &Timespec) -> Timespec {
+        self.realtime
+            .checked_add_signed(&m.signed_sub(&self.monotonic))
+            .expect("overflow when mapping monotonic time to realtime")
+    }
+}
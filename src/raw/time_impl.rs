@@ -0,0 +1,34 @@
+use time::{error::ComponentRange, OffsetDateTime};
+
+use super::Timespec;
+
+impl Timespec {
+    /// Converts a [`ClockId::Realtime`] reading to a UTC `OffsetDateTime`,
+    /// failing if it is out of the range supported by the `time` crate.
+    ///
+    /// [`ClockId::Realtime`]: super::ClockId::Realtime
+    ///
+    /// ```
+    /// use time::OffsetDateTime;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(-2, 123_456_789);
+    /// let dt = t.to_offset_datetime().unwrap();
+    /// assert_eq!(dt, OffsetDateTime::from_unix_timestamp_nanos(-1_876_543_211).unwrap());
+    /// assert_eq!(Timespec::from_offset_datetime(&dt), t);
+    /// assert!(Timespec::MAX.to_offset_datetime().is_err());
+    /// ```
+    #[inline]
+    pub fn to_offset_datetime(&self) -> Result<OffsetDateTime, ComponentRange> {
+        OffsetDateTime::from_unix_timestamp_nanos(self.as_nanos())
+    }
+
+    /// Converts an `OffsetDateTime` to the corresponding
+    /// [`ClockId::Realtime`] reading.
+    ///
+    /// [`ClockId::Realtime`]: super::ClockId::Realtime
+    #[inline]
+    pub fn from_offset_datetime(dt: &OffsetDateTime) -> Self {
+        Self::new(dt.unix_timestamp(), dt.nanosecond())
+    }
+}
@@ -0,0 +1,144 @@
+use cfg_if::cfg_if;
+use linux_syscalls::{syscall, Errno, Sysno};
+
+use super::{ClockId, Itimerspec, Timespec};
+
+cfg_if! {
+    if #[cfg(any(target_arch = "x86_64", target_pointer_width = "64"))] {
+        #[allow(non_upper_case_globals)]
+        const SYS_timer_settime: Sysno = Sysno::timer_settime;
+        #[allow(non_upper_case_globals)]
+        const SYS_timer_gettime: Sysno = Sysno::timer_gettime;
+    } else {
+        #[allow(non_upper_case_globals)]
+        const SYS_timer_settime: Sysno = Sysno::timer_settime64;
+        #[allow(non_upper_case_globals)]
+        const SYS_timer_gettime: Sysno = Sysno::timer_gettime64;
+    }
+}
+
+const SIGEV_PAD: usize = (64 - 2 * core::mem::size_of::<i32>() - core::mem::size_of::<usize>()) / 4;
+
+// The kernel `sigevent`, padded to its fixed 64 bytes size.
+#[repr(C)]
+struct Sigevent {
+    sigev_value: usize,
+    sigev_signo: i32,
+    sigev_notify: i32,
+    __pad: [i32; SIGEV_PAD],
+}
+
+/// A POSIX per-process timer (`timer_create`) delivering a signal to the
+/// process on every expiration.
+///
+/// The alarm clocks ([`ClockId::RealtimeAlarm`] and
+/// [`ClockId::BoottimeAlarm`]) wake the system up from suspend on
+/// expiration, creating a timer against them requires the
+/// `CAP_WAKE_ALARM` capability and fails with `EPERM` otherwise, or with
+/// `EOPNOTSUPP` if the machine has no RTC able to wake it up.
+///
+/// The timer is deleted on drop.
+///
+/// ```
+/// use unix_clock::{raw::{ClockId, Timer, Timespec}, Errno};
+///
+/// const SIGALRM: i32 = linux_raw_sys::general::SIGALRM as i32;
+///
+/// for clockid in [ClockId::Monotonic, ClockId::BoottimeAlarm] {
+///     let timer = match Timer::new(clockid, SIGALRM) {
+///         Ok(timer) => timer,
+///         // Missing CAP_WAKE_ALARM or a wake-capable RTC.
+///         Err(Errno::EPERM | Errno::EOPNOTSUPP) if clockid == ClockId::BoottimeAlarm => continue,
+///         Err(err) => panic!("{:?}", err),
+///     };
+///     // Far enough in the future to be deleted before delivering SIGALRM.
+///     timer.set(&Timespec::new(600, 0), &Timespec::new(1, 0), false).unwrap();
+///     let curr = timer.get().unwrap();
+///     assert_eq!(curr.it_interval, Timespec::new(1, 0));
+///     assert!(curr.it_value > Timespec::new(599, 0));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Timer(i32);
+
+impl Timer {
+    /// Creates a new disarmed timer against the clock `clockid`, sending
+    /// `signal` to the process on expiration. `clockid` must be one of
+    /// [`ClockId::Realtime`], [`ClockId::Monotonic`], [`ClockId::Boottime`],
+    /// [`ClockId::RealtimeAlarm`] or [`ClockId::BoottimeAlarm`], fails with
+    /// `EINVAL` otherwise.
+    pub fn new(clockid: ClockId, signal: i32) -> Result<Self, Errno> {
+        if !matches!(
+            clockid,
+            ClockId::Realtime
+                | ClockId::Monotonic
+                | ClockId::Boottime
+                | ClockId::RealtimeAlarm
+                | ClockId::BoottimeAlarm
+        ) {
+            return Err(Errno::EINVAL);
+        }
+
+        let sev = Sigevent {
+            sigev_value: 0,
+            sigev_signo: signal,
+            sigev_notify: linux_raw_sys::general::SIGEV_SIGNAL as i32,
+            __pad: [0; SIGEV_PAD],
+        };
+        let mut id = 0i32;
+        unsafe {
+            syscall!(
+                Sysno::timer_create,
+                clockid.as_raw(),
+                &sev as *const Sigevent,
+                &mut id as *mut i32
+            )
+        }
+        .map(|_| Self(id))
+    }
+
+    /// Arms the timer to expire after `initial` (or at `initial` when `abs`
+    /// is `true`) and then every `interval`. A zero `initial` disarms it, a
+    /// zero `interval` makes it a one-shot timer.
+    pub fn set(&self, initial: &Timespec, interval: &Timespec, abs: bool) -> Result<(), Errno> {
+        let new = Itimerspec {
+            it_interval: *interval,
+            it_value: *initial,
+        };
+        let flags = if abs {
+            linux_raw_sys::general::TIMER_ABSTIME
+        } else {
+            0
+        };
+
+        unsafe {
+            syscall!(
+                [ro] SYS_timer_settime,
+                self.0,
+                flags,
+                &new as *const Itimerspec,
+                core::ptr::null_mut::<Itimerspec>()
+            )
+        }
+        .map(|_| ())
+    }
+
+    /// Returns the current setting of the timer, with `it_value` relative to
+    /// now even if the timer has been armed with an absolute time.
+    pub fn get(&self) -> Result<Itimerspec, Errno> {
+        let mut curr = Itimerspec::default();
+        unsafe { syscall!(SYS_timer_gettime, self.0, &mut curr as *mut Itimerspec) }.map(|_| curr)
+    }
+
+    /// Returns the kernel id of the timer.
+    #[inline]
+    pub const fn as_raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        _ = unsafe { syscall!([ro] Sysno::timer_delete, self.0) };
+    }
+}
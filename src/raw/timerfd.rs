@@ -0,0 +1,168 @@
+use cfg_if::cfg_if;
+use linux_syscalls::{syscall, Errno, Sysno};
+
+use super::{retry_on_eintr, ClockId, Timespec};
+
+cfg_if! {
+    if #[cfg(any(target_arch = "x86_64", target_pointer_width = "64"))] {
+        #[allow(non_upper_case_globals)]
+        const SYS_timerfd_settime: Sysno = Sysno::timerfd_settime;
+        #[allow(non_upper_case_globals)]
+        const SYS_timerfd_gettime: Sysno = Sysno::timerfd_gettime;
+    } else {
+        #[allow(non_upper_case_globals)]
+        const SYS_timerfd_settime: Sysno = Sysno::timerfd_settime64;
+        #[allow(non_upper_case_globals)]
+        const SYS_timerfd_gettime: Sysno = Sysno::timerfd_gettime64;
+    }
+}
+
+/// The expiration settings of a timer (the kernel `__kernel_itimerspec`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Itimerspec {
+    /// The period of the timer, zero for a one-shot timer.
+    pub it_interval: Timespec,
+    /// The time until the next expiration, zero if the timer is disarmed.
+    pub it_value: Timespec,
+}
+
+/// Flags for [`TimerFd::new`], they can be combined with `|`.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TimerFdFlags(u32);
+
+impl TimerFdFlags {
+    /// No flags.
+    pub const EMPTY: Self = Self(0);
+    /// Close the file descriptor on `execve` (TFD_CLOEXEC).
+    pub const CLOEXEC: Self = Self(linux_raw_sys::general::TFD_CLOEXEC);
+    /// Reads don't block when the timer didn't expire yet, failing with
+    /// `EAGAIN` instead (TFD_NONBLOCK).
+    pub const NONBLOCK: Self = Self(linux_raw_sys::general::TFD_NONBLOCK);
+}
+
+impl core::ops::BitOr for TimerFdFlags {
+    type Output = TimerFdFlags;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for TimerFdFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A timer notifying expirations through a file descriptor (`timerfd`),
+/// so it can be monitored by `poll`, `select` and `epoll`.
+///
+/// The file descriptor is closed on drop.
+///
+/// ```
+/// use unix_clock::raw::{ClockId, TimerFd, TimerFdFlags, Timespec};
+///
+/// let timer = TimerFd::new(ClockId::Monotonic, TimerFdFlags::CLOEXEC).unwrap();
+/// timer
+///     .set(&Timespec::new(0, 20_000_000), &Timespec::zero(), false)
+///     .unwrap();
+/// assert_eq!(timer.read().unwrap(), 1);
+/// assert_eq!(timer.get().unwrap().it_value, Timespec::zero());
+/// ```
+#[derive(Debug)]
+pub struct TimerFd(i32);
+
+impl TimerFd {
+    /// Creates a new disarmed timer against the clock `clockid`, which must
+    /// be one of [`ClockId::Realtime`], [`ClockId::Monotonic`],
+    /// [`ClockId::Boottime`], [`ClockId::RealtimeAlarm`] or
+    /// [`ClockId::BoottimeAlarm`]. Fails with `EINVAL` otherwise.
+    pub fn new(clockid: ClockId, flags: TimerFdFlags) -> Result<Self, Errno> {
+        if !matches!(
+            clockid,
+            ClockId::Realtime
+                | ClockId::Monotonic
+                | ClockId::Boottime
+                | ClockId::RealtimeAlarm
+                | ClockId::BoottimeAlarm
+        ) {
+            return Err(Errno::EINVAL);
+        }
+
+        unsafe { syscall!([ro] Sysno::timerfd_create, clockid.as_raw(), flags.0) }
+            .map(|fd| Self(fd as i32))
+    }
+
+    /// Arms the timer to expire after `initial` (or at `initial` when `abs`
+    /// is `true`) and then every `interval`. A zero `initial` disarms it, a
+    /// zero `interval` makes it a one-shot timer.
+    pub fn set(&self, initial: &Timespec, interval: &Timespec, abs: bool) -> Result<(), Errno> {
+        let new = Itimerspec {
+            it_interval: *interval,
+            it_value: *initial,
+        };
+        let flags = if abs {
+            linux_raw_sys::general::TFD_TIMER_ABSTIME
+        } else {
+            0
+        };
+
+        unsafe {
+            syscall!(
+                [ro] SYS_timerfd_settime,
+                self.0,
+                flags,
+                &new as *const Itimerspec,
+                core::ptr::null_mut::<Itimerspec>()
+            )
+        }
+        .map(|_| ())
+    }
+
+    /// Returns the current setting of the timer, with `it_value` relative to
+    /// now even if the timer has been armed with an absolute time.
+    pub fn get(&self) -> Result<Itimerspec, Errno> {
+        let mut curr = Itimerspec::default();
+        unsafe { syscall!(SYS_timerfd_gettime, self.0, &mut curr as *mut Itimerspec) }.map(|_| curr)
+    }
+
+    /// Returns the number of expirations since the last read, blocking until
+    /// the next one if there is none yet (unless the timer has been created
+    /// with [`TimerFdFlags::NONBLOCK`]). Signal interruptions are retried.
+    pub fn read(&self) -> Result<u64, Errno> {
+        let mut expirations = 0u64;
+        retry_on_eintr(|| unsafe {
+            syscall!(
+                Sysno::read,
+                self.0,
+                &mut expirations as *mut u64,
+                core::mem::size_of::<u64>()
+            )
+        })
+        .map(|_| expirations)
+    }
+
+    /// Returns the underlying file descriptor.
+    #[inline]
+    pub const fn as_raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Drop for TimerFd {
+    fn drop(&mut self) {
+        _ = unsafe { syscall!([ro] Sysno::close, self.0) };
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::os::unix::io::AsRawFd for TimerFd {
+    #[inline]
+    fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        self.0
+    }
+}
@@ -0,0 +1,98 @@
+use super::Timespec;
+#[cfg(any(feature = "libc", not(target_os = "linux")))]
+use crate::Errno;
+
+const USEC_PER_SEC: i64 = 1_000_000;
+
+/// A time value with microseconds precision, like the `struct timeval` used
+/// by `gettimeofday` (the kernel `__kernel_timex_timeval` on Linux).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Timeval {
+    pub tv_sec: i64,
+    pub tv_usec: i64,
+}
+
+impl Timeval {
+    /// Converts to a [`Timespec`], carrying the microseconds exceeding a
+    /// second (or negative) into the seconds. Clamps to [`Timespec::MIN`] or
+    /// [`Timespec::MAX`] if the carry overflows the seconds.
+    ///
+    /// ```
+    /// use unix_clock::raw::{Timespec, Timeval};
+    ///
+    /// let tv = Timeval { tv_sec: 1, tv_usec: 999_999 };
+    /// assert_eq!(tv.to_timespec(), Timespec::new(1, 999_999_000));
+    /// let tv = Timeval { tv_sec: 1, tv_usec: 1_000_000 };
+    /// assert_eq!(tv.to_timespec(), Timespec::new(2, 0));
+    /// let tv = Timeval { tv_sec: i64::MAX, tv_usec: 999_999 };
+    /// assert_eq!(tv.to_timespec(), Timespec::new(i64::MAX, 999_999_000));
+    /// let tv = Timeval { tv_sec: i64::MAX, tv_usec: 1_000_000 };
+    /// assert_eq!(tv.to_timespec(), Timespec::MAX);
+    /// let tv = Timeval { tv_sec: i64::MIN, tv_usec: 0 };
+    /// assert_eq!(tv.to_timespec(), Timespec::MIN);
+    /// let tv = Timeval { tv_sec: i64::MIN, tv_usec: -1 };
+    /// assert_eq!(tv.to_timespec(), Timespec::MIN);
+    /// ```
+    pub const fn to_timespec(&self) -> Timespec {
+        let carry = self.tv_usec.div_euclid(USEC_PER_SEC);
+        match self.tv_sec.checked_add(carry) {
+            Some(secs) => {
+                Timespec::new(secs, (self.tv_usec.rem_euclid(USEC_PER_SEC) * 1_000) as u32)
+            }
+            None if carry < 0 => Timespec::MIN,
+            None => Timespec::MAX,
+        }
+    }
+}
+
+impl Timespec {
+    /// Converts to a [`Timeval`], truncating the nanoseconds to microseconds.
+    /// As the nanoseconds are never negative this rounds toward negative
+    /// infinity.
+    ///
+    /// ```
+    /// use unix_clock::raw::{Timespec, Timeval};
+    ///
+    /// assert_eq!(
+    ///     Timespec::new(1, 999_999_999).to_timeval(),
+    ///     Timeval { tv_sec: 1, tv_usec: 999_999 }
+    /// );
+    /// assert_eq!(
+    ///     Timespec::new(-1, 1_999).to_timeval(),
+    ///     Timeval { tv_sec: -1, tv_usec: 1 }
+    /// );
+    /// ```
+    pub const fn to_timeval(&self) -> Timeval {
+        Timeval {
+            tv_sec: self.secs(),
+            tv_usec: (self.nsecs() / 1_000) as i64,
+        }
+    }
+}
+
+#[cfg(any(feature = "libc", not(target_os = "linux")))]
+impl From<libc::timeval> for Timeval {
+    #[allow(clippy::unnecessary_cast)]
+    fn from(value: libc::timeval) -> Self {
+        Self {
+            tv_sec: value.tv_sec as i64,
+            tv_usec: value.tv_usec as i64,
+        }
+    }
+}
+
+/// Converts to a `libc::timeval`, failing with `EOVERFLOW` if the fields
+/// don't fit in the target's types.
+#[cfg(any(feature = "libc", not(target_os = "linux")))]
+impl TryFrom<Timeval> for libc::timeval {
+    type Error = Errno;
+
+    #[allow(clippy::useless_conversion)]
+    fn try_from(value: Timeval) -> Result<Self, Self::Error> {
+        Ok(libc::timeval {
+            tv_sec: value.tv_sec.try_into().map_err(|_| Errno::EOVERFLOW)?,
+            tv_usec: value.tv_usec.try_into().map_err(|_| Errno::EOVERFLOW)?,
+        })
+    }
+}
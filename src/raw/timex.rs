@@ -0,0 +1,240 @@
+use core::time::Duration;
+
+use cfg_if::cfg_if;
+use linux_syscalls::{syscall, Errno, Sysno};
+
+use super::{ClockId, SleepFlags, Timespec, Timeval};
+
+cfg_if! {
+    if #[cfg(any(target_arch = "x86_64", target_pointer_width = "64"))] {
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_adjtime: Sysno = Sysno::clock_adjtime;
+    } else {
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_adjtime: Sysno = Sysno::clock_adjtime64;
+    }
+}
+
+/// The kernel clock adjustment parameters (the kernel `__kernel_timex`).
+///
+/// Fields are only written by the kernel when `modes` is zero, see the
+/// adjtimex(2) man page for their meaning.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timex {
+    /// Mode selector.
+    pub modes: u32,
+    __pad0: i32,
+    /// Time offset (usec).
+    pub offset: i64,
+    /// Frequency offset (scaled ppm).
+    pub freq: i64,
+    /// Maximum error (usec).
+    pub maxerror: i64,
+    /// Estimated error (usec).
+    pub esterror: i64,
+    /// Clock command/status.
+    pub status: i32,
+    __pad1: i32,
+    /// PLL time constant.
+    pub constant: i64,
+    /// Clock precision (usec).
+    pub precision: i64,
+    /// Clock frequency tolerance (ppm).
+    pub tolerance: i64,
+    /// Current time.
+    pub time: Timeval,
+    /// Microseconds between clock ticks.
+    pub tick: i64,
+    /// PPS frequency (scaled ppm).
+    pub ppsfreq: i64,
+    /// PPS jitter (usec).
+    pub jitter: i64,
+    /// PPS interval duration (seconds, shift).
+    pub shift: i32,
+    __pad2: i32,
+    /// PPS stability (scaled ppm).
+    pub stabil: i64,
+    /// PPS jitter limit exceeded counter.
+    pub jitcnt: i64,
+    /// PPS calibration intervals counter.
+    pub calcnt: i64,
+    /// PPS calibration errors counter.
+    pub errcnt: i64,
+    /// PPS stability limit exceeded counter.
+    pub stbcnt: i64,
+    /// TAI offset (seconds).
+    pub tai: i32,
+    __reserved: [i32; 11],
+}
+
+/// The clock state returned by [`adjtime`].
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AdjtimeState {
+    /// Clock synchronized, no leap second adjustment pending (TIME_OK).
+    Ok = 0,
+    /// A leap second will be inserted at the end of the UTC day (TIME_INS).
+    Insert = 1,
+    /// A leap second will be deleted at the end of the UTC day (TIME_DEL).
+    Delete = 2,
+    /// Insertion of a leap second is in progress (TIME_OOP).
+    InProgress = 3,
+    /// A leap-second insertion or deletion has been completed (TIME_WAIT).
+    Wait = 4,
+    /// The system clock is not synchronized to a reliable server
+    /// (TIME_ERROR).
+    Error = 5,
+}
+
+/// Reads or tunes the adjustment parameters of the clock `clockid` via
+/// `clock_adjtime`. With `tx.modes` set to zero this is a read-only
+/// operation and doesn't require any privilege.
+///
+/// ```
+/// use unix_clock::raw::{adjtime, ClockId, Timex};
+///
+/// let mut tx = Timex::default();
+/// adjtime(ClockId::Realtime, &mut tx).unwrap();
+/// assert!(tx.tick > 0);
+/// ```
+pub fn adjtime(clockid: ClockId, tx: &mut Timex) -> Result<AdjtimeState, Errno> {
+    unsafe { syscall!(SYS_clock_adjtime, clockid.as_raw(), tx as *mut Timex) }.map(|state| {
+        match state {
+            0 => AdjtimeState::Ok,
+            1 => AdjtimeState::Insert,
+            2 => AdjtimeState::Delete,
+            3 => AdjtimeState::InProgress,
+            4 => AdjtimeState::Wait,
+            _ => AdjtimeState::Error,
+        }
+    })
+}
+
+/// Returns the frequency offset of the clock `clockid` in parts per million,
+/// as read by a read-only [`adjtime`] call.
+///
+/// ```
+/// use unix_clock::raw::{clock_frequency_ppm, ClockId};
+///
+/// assert!(clock_frequency_ppm(ClockId::Realtime).unwrap().is_finite());
+/// ```
+pub fn clock_frequency_ppm(clockid: ClockId) -> Result<f64, Errno> {
+    let mut tx = Timex::default();
+    adjtime(clockid, &mut tx)?;
+    // `freq` is in ppm with a 16-bit fractional part.
+    Ok(tx.freq as f64 / 65536.0)
+}
+
+const STA_INS: i32 = 0x0010;
+const STA_DEL: i32 = 0x0020;
+
+/// The leap second state of the system clock, see [`leap_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LeapState {
+    /// No leap second is pending.
+    Normal,
+    /// A leap second will be inserted at the end of the UTC day.
+    InsertPending,
+    /// A leap second will be deleted at the end of the UTC day.
+    DeletePending,
+    /// A leap second is being inserted, or has just been.
+    Inserting,
+    /// A leap second has just been deleted.
+    Deleting,
+}
+
+/// Returns the leap second state of the system clock, from the state
+/// returned by a read-only [`adjtime`] call and the `STA_INS`/`STA_DEL`
+/// status flags.
+///
+/// ```
+/// use unix_clock::raw::{leap_state, LeapState};
+///
+/// let state = leap_state().unwrap();
+/// assert!(matches!(
+///     state,
+///     LeapState::Normal
+///         | LeapState::InsertPending
+///         | LeapState::DeletePending
+///         | LeapState::Inserting
+///         | LeapState::Deleting
+/// ));
+/// ```
+pub fn leap_state() -> Result<LeapState, Errno> {
+    let mut tx = Timex::default();
+    let state = adjtime(ClockId::Realtime, &mut tx)?;
+    let flags = (tx.status & STA_INS != 0, tx.status & STA_DEL != 0);
+    Ok(match (state, flags) {
+        (AdjtimeState::Insert, _) => LeapState::InsertPending,
+        (AdjtimeState::Delete, _) => LeapState::DeletePending,
+        (AdjtimeState::InProgress, _) => LeapState::Inserting,
+        (AdjtimeState::Wait, (true, _)) => LeapState::Inserting,
+        (AdjtimeState::Wait, (_, true)) => LeapState::Deleting,
+        // An unsynchronized clock hides the leap state behind TIME_ERROR.
+        (AdjtimeState::Error, (true, _)) => LeapState::InsertPending,
+        (AdjtimeState::Error, (_, true)) => LeapState::DeletePending,
+        _ => LeapState::Normal,
+    })
+}
+
+/// Returns the current offset between [`ClockId::InternationalAtomicTime`]
+/// and [`ClockId::Realtime`], i.e. the number of leap seconds since 1972
+/// plus the initial 10 seconds. The two clocks are read back to back and the
+/// difference is rounded to whole seconds.
+///
+/// The kernel only knows the offset once it has been set through
+/// [`adjtime`] (usually by the NTP daemon), it's zero until then.
+///
+/// ```
+/// use unix_clock::raw::tai_utc_offset;
+///
+/// let offset = tai_utc_offset().unwrap();
+/// assert_eq!(offset.subsec_nanos(), 0);
+/// // 37s since 2017, unless the offset has not been set yet.
+/// assert!(offset.as_secs() == 0 || (30..=40).contains(&offset.as_secs()));
+/// ```
+pub fn tai_utc_offset() -> Result<Duration, Errno> {
+    let (utc, tai) = Timespec::now_pair(ClockId::Realtime, ClockId::InternationalAtomicTime)?;
+    // Realtime is read first so the difference is never short of the offset,
+    // unless the clock got stepped in between.
+    let diff = tai.sub_timespec(&utc).unwrap_or_default();
+    Ok(Duration::from_secs(
+        diff.as_secs() + (diff.subsec_nanos() >= 500_000_000) as u64,
+    ))
+}
+
+/// Returns `true` if [`ClockId::MonotonicRaw`] runs at a measurably different
+/// rate than [`ClockId::Monotonic`], i.e. if the frequency adjustment applied
+/// to the latter (see [`clock_frequency_ppm`]) doesn't apply to the former.
+///
+/// Both clocks are sampled 50ms apart, which blocks the calling thread.
+/// Rates that differ less than 1ppm, plus the jitter of the readings, count
+/// as the same: it's the case of kernels aliasing the two clocks, and of
+/// clocks that are not being disciplined by NTP at all.
+///
+/// ```
+/// let _: bool = unix_clock::raw::monotonic_raw_is_distinct().unwrap();
+/// ```
+pub fn monotonic_raw_is_distinct() -> Result<bool, Errno> {
+    const INTERVAL: Timespec = Timespec::from_millis(50);
+
+    fn sample() -> Result<(i128, i128, i128), Errno> {
+        let mono = Timespec::now(ClockId::Monotonic)?;
+        let raw = Timespec::now(ClockId::MonotonicRaw)?;
+        let end = Timespec::now(ClockId::Monotonic)?;
+        Ok((
+            mono.as_nanos(),
+            raw.as_nanos(),
+            end.as_nanos() - mono.as_nanos(),
+        ))
+    }
+
+    let (mono0, raw0, jitter0) = sample()?;
+    Timespec::sleep_uninterrupted(ClockId::Monotonic, SleepFlags::Relative, &INTERVAL)?;
+    let (mono1, raw1, jitter1) = sample()?;
+
+    let elapsed = mono1 - mono0;
+    let skew = elapsed - (raw1 - raw0);
+    Ok(skew.abs() > elapsed / 1_000_000 + jitter0 + jitter1)
+}
@@ -0,0 +1,87 @@
+use core::time::Duration;
+
+use crate::{
+    raw::{ClockId, Timespec},
+    Errno,
+};
+
+#[cfg(any(target_os = "linux", target_os = "openbsd"))]
+const UPTIME_CLOCKID: ClockId = ClockId::Boottime;
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+const UPTIME_CLOCKID: ClockId = ClockId::Monotonic;
+
+#[cfg(target_os = "linux")]
+const UPTIME_AWAKE_CLOCKID: ClockId = ClockId::Monotonic;
+#[cfg(target_os = "openbsd")]
+const UPTIME_AWAKE_CLOCKID: ClockId = ClockId::Uptime;
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+const UPTIME_AWAKE_CLOCKID: ClockId = ClockId::UptimeRaw;
+
+#[inline(always)]
+fn read(clockid: ClockId) -> Result<Duration, Errno> {
+    // These clocks start at boot, they can't be before their epoch.
+    Timespec::now(clockid).map(|t| t.as_duration().unwrap_or_default())
+}
+
+/// Returns the time elapsed since the system booted, including the time it
+/// spent suspended.
+///
+/// ```
+/// let awake = unix_clock::uptime_awake().unwrap();
+/// assert!(unix_clock::uptime().unwrap() >= awake);
+/// ```
+#[inline]
+pub fn uptime() -> Result<Duration, Errno> {
+    read(UPTIME_CLOCKID)
+}
+
+/// Returns the time elapsed since the system booted, excluding the time it
+/// spent suspended.
+#[inline]
+pub fn uptime_awake() -> Result<Duration, Errno> {
+    read(UPTIME_AWAKE_CLOCKID)
+}
+
+/// Returns the total time the system spent suspended since it booted.
+///
+/// The two clocks involved can't be read atomically, tiny negative skews are
+/// reported as zero.
+///
+/// ```
+/// let suspended = unix_clock::suspended_time().unwrap();
+/// assert!(suspended <= unix_clock::uptime().unwrap());
+/// ```
+pub fn suspended_time() -> Result<Duration, Errno> {
+    let (total, awake) = Timespec::now_pair(UPTIME_CLOCKID, UPTIME_AWAKE_CLOCKID)?;
+    Ok(total.sub_timespec(&awake).unwrap_or_default())
+}
+
+/// Returns the two values of `/proc/uptime` without going through procfs:
+/// the time elapsed since boot (see [`uptime`]) and the time the CPUs spent
+/// idle.
+///
+/// No clock measures the idle time of the CPUs, only the scheduler
+/// statistics do, so the second value is always zero: it's there to mirror
+/// the format.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// let (total, idle) = unix_clock::proc_uptime().unwrap();
+/// assert!(total > Duration::ZERO);
+/// assert_eq!(idle, Duration::ZERO);
+/// ```
+#[inline]
+pub fn proc_uptime() -> Result<(Duration, Duration), Errno> {
+    Ok((uptime()?, Duration::ZERO))
+}
@@ -0,0 +1,82 @@
+#![cfg(target_os = "linux")]
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use unix_clock::raw::{ClockId, SleepFlags, Timespec};
+
+static SIGNALS: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn on_signal(_: i32) {
+    SIGNALS.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Installs a `SIGUSR1` handler without `SA_RESTART`, so that the signal
+/// interrupts the blocking calls, and sends it to the calling thread `count`
+/// times every `every` from another thread.
+fn interrupt(count: usize, every: Duration) -> thread::JoinHandle<()> {
+    let target = unsafe {
+        let mut action: libc::sigaction = core::mem::zeroed();
+        action.sa_sigaction = on_signal as extern "C" fn(i32) as libc::sighandler_t;
+        libc::sigemptyset(&mut action.sa_mask);
+        assert_eq!(
+            libc::sigaction(libc::SIGUSR1, &action, core::ptr::null_mut()),
+            0
+        );
+        libc::syscall(libc::SYS_gettid)
+    };
+    thread::spawn(move || {
+        for _ in 0..count {
+            thread::sleep(every);
+            unsafe { libc::syscall(libc::SYS_tgkill, libc::getpid(), target, libc::SIGUSR1) };
+        }
+    })
+}
+
+#[test]
+fn sleep_returns_the_remainder() {
+    let before = SIGNALS.load(Ordering::SeqCst);
+    let signals = interrupt(1, Duration::from_millis(50));
+    let request = Timespec::new(5, 0);
+    let remain = Timespec::sleep(ClockId::Monotonic, SleepFlags::Relative, &request).unwrap();
+    signals.join().unwrap();
+
+    assert!(SIGNALS.load(Ordering::SeqCst) > before);
+    let remain = remain.expect("the sleep should have been interrupted");
+    assert!(remain < request && remain > Timespec::new(4, 0));
+}
+
+#[test]
+fn sleep_uninterrupted_relative_completes() {
+    let before = SIGNALS.load(Ordering::SeqCst);
+    let start = Timespec::now(ClockId::Monotonic).unwrap();
+    let signals = interrupt(5, Duration::from_millis(20));
+    Timespec::sleep_uninterrupted(
+        ClockId::Monotonic,
+        SleepFlags::Relative,
+        &Timespec::new(0, 300_000_000),
+    )
+    .unwrap();
+    let elapsed = start.elapsed(ClockId::Monotonic).unwrap();
+    signals.join().unwrap();
+
+    assert!(SIGNALS.load(Ordering::SeqCst) > before);
+    assert!(elapsed >= Duration::from_millis(300), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+}
+
+#[test]
+fn sleep_until_completes() {
+    let before = SIGNALS.load(Ordering::SeqCst);
+    let deadline = Timespec::now(ClockId::Monotonic).unwrap() + Duration::from_millis(300);
+    let signals = interrupt(5, Duration::from_millis(20));
+    Timespec::sleep_until(ClockId::Monotonic, &deadline).unwrap();
+    let end = Timespec::now(ClockId::Monotonic).unwrap();
+    signals.join().unwrap();
+
+    assert!(SIGNALS.load(Ordering::SeqCst) > before);
+    assert!(end >= deadline);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "mock")]
+
+use unix_clock::raw::{mock, ClockId, Timespec};
+
+/// Encodes the clock asked for in the seconds, to tell which one has been
+/// read.
+fn fake(clockid: ClockId) -> Result<Timespec, unix_clock::Errno> {
+    let secs = ClockId::all().iter().position(|id| *id == clockid).unwrap();
+    Ok(Timespec::new(1_000 + secs as i64, 0))
+}
+
+// Everything runs in a single test, the override is process-wide.
+#[test]
+fn clock_source_override() {
+    let faked = |id| fake(id).unwrap();
+
+    mock::set_clock_source(fake);
+
+    assert_eq!(
+        Timespec::now(ClockId::Monotonic),
+        Ok(faked(ClockId::Monotonic))
+    );
+    assert_eq!(
+        Timespec::now(ClockId::Realtime),
+        Ok(faked(ClockId::Realtime))
+    );
+
+    // No real clock is that close to the epoch.
+    let mut t = Timespec::zero();
+    Timespec::now_into(ClockId::Realtime, &mut t).unwrap();
+    assert!(t > Timespec::new(1_000_000_000, 0));
+
+    #[cfg(target_os = "linux")]
+    {
+        use unix_clock::raw::PrecisionMode;
+
+        assert_eq!(
+            Timespec::now_coarse_monotonic(),
+            Ok(faked(ClockId::MonotonicCoarse))
+        );
+        assert_eq!(
+            Timespec::now_coarse_realtime(),
+            Ok(faked(ClockId::RealtimeCoarse))
+        );
+        assert_eq!(
+            Timespec::now_mode(ClockId::Monotonic, PrecisionMode::Coarse),
+            Ok(faked(ClockId::MonotonicCoarse))
+        );
+
+        let (t, _) = Timespec::now_with_source(ClockId::Realtime).unwrap();
+        assert!(t > Timespec::new(1_000_000_000, 0));
+    }
+
+    mock::reset_clock_source();
+    assert!(Timespec::now(ClockId::Realtime).unwrap() > Timespec::new(1_000_000_000, 0));
+}
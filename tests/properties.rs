@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use unix_clock::raw::Timespec;
+
+const NSECS: [u32; 3] = [0, 1, 999_999_999];
+
+/// Seconds around zero and the edges of the range, where the arithmetic
+/// carries and overflows.
+fn secs() -> impl Iterator<Item = i64> {
+    (i64::MIN..i64::MIN + 4)
+        .chain(-4..4)
+        .chain(i64::MAX - 4..=i64::MAX)
+}
+
+/// Normalized `Timespec`s, nanoseconds are always less than a second.
+fn timespecs() -> impl Iterator<Item = Timespec> {
+    secs().flat_map(|secs| NSECS.iter().map(move |&nsecs| Timespec::new(secs, nsecs)))
+}
+
+fn durations() -> impl Iterator<Item = Duration> {
+    (0u64..4)
+        .chain(i64::MAX as u64 - 4..=i64::MAX as u64 + 4)
+        .chain(u64::MAX - 4..=u64::MAX)
+        .flat_map(|secs| NSECS.iter().map(move |&nsecs| Duration::new(secs, nsecs)))
+}
+
+#[test]
+fn add_then_sub_duration() {
+    for x in timespecs() {
+        for d in durations() {
+            if let Some(y) = x.checked_add_duration(&d) {
+                assert_eq!(y.checked_sub_duration(&d), Some(x), "{x:?} + {d:?}");
+                assert_eq!(y.sub_timespec(&x), Ok(d), "{x:?} + {d:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn sub_then_add_duration() {
+    for x in timespecs() {
+        for d in durations() {
+            if let Some(y) = x.checked_sub_duration(&d) {
+                assert_eq!(y.checked_add_duration(&d), Some(x), "{x:?} - {d:?}");
+                assert_eq!(x.sub_timespec(&y), Ok(d), "{x:?} - {d:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn sub_timespec_antisymmetric() {
+    for x in timespecs() {
+        for y in timespecs() {
+            match x.sub_timespec(&y) {
+                Ok(d) if d.is_zero() => {
+                    assert_eq!(x, y);
+                    assert_eq!(y.sub_timespec(&x), Ok(d), "{x:?} - {y:?}");
+                }
+                Ok(d) => assert_eq!(y.sub_timespec(&x), Err(d), "{x:?} - {y:?}"),
+                Err(d) => assert_eq!(y.sub_timespec(&x), Ok(d), "{x:?} - {y:?}"),
+            }
+        }
+    }
+}
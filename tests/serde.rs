@@ -0,0 +1,132 @@
+#![cfg(feature = "serde")]
+
+use unix_clock::raw::{ClockId, Timespec};
+
+const SAMPLES: &[Timespec] = &[
+    Timespec::MIN,
+    Timespec::new(-1, 999_999_999),
+    Timespec::new(0, 0),
+    Timespec::new(1_700_000_000, 123_456_789),
+    Timespec::MAX,
+];
+
+#[test]
+fn timespec_json_round_trip() {
+    for t in SAMPLES {
+        let json = serde_json::to_string(t).unwrap();
+        assert_eq!(serde_json::from_str::<Timespec>(&json).unwrap(), *t);
+    }
+    assert_eq!(
+        serde_json::to_string(&Timespec::new(1, 2)).unwrap(),
+        r#"{"secs":1,"nsecs":2}"#
+    );
+}
+
+#[test]
+fn timespec_bincode_round_trip() {
+    for t in SAMPLES {
+        let bytes = bincode::serialize(t).unwrap();
+        assert_eq!(bincode::deserialize::<Timespec>(&bytes).unwrap(), *t);
+    }
+}
+
+#[test]
+fn timespec_rejects_unnormalized_nsecs() {
+    assert!(serde_json::from_str::<Timespec>(r#"{"secs":1,"nsecs":999999999}"#).is_ok());
+    assert!(serde_json::from_str::<Timespec>(r#"{"secs":1,"nsecs":1000000000}"#).is_err());
+    assert!(serde_json::from_str::<Timespec>("[1,1000000000]").is_err());
+
+    let bytes = bincode::serialize(&(1i64, 1_000_000_000u32)).unwrap();
+    assert!(bincode::deserialize::<Timespec>(&bytes).is_err());
+}
+
+#[test]
+fn clock_id_json_round_trip() {
+    for id in ClockId::all() {
+        let json = serde_json::to_string(id).unwrap();
+        assert_eq!(serde_json::from_str::<ClockId>(&json).unwrap(), *id);
+    }
+}
+
+#[test]
+fn clock_id_bincode_round_trip() {
+    for id in ClockId::all() {
+        let bytes = bincode::serialize(id).unwrap();
+        assert_eq!(bincode::deserialize::<ClockId>(&bytes).unwrap(), *id);
+    }
+}
+
+#[test]
+fn clock_id_lowercase_name() {
+    assert_eq!(
+        serde_json::to_string(&ClockId::Realtime).unwrap(),
+        r#""realtime""#
+    );
+    assert_eq!(
+        serde_json::to_string(&ClockId::Monotonic).unwrap(),
+        r#""monotonic""#
+    );
+    for id in ClockId::all() {
+        let json = serde_json::to_string(id).unwrap();
+        assert_eq!(json, json.to_ascii_lowercase());
+    }
+}
+
+#[test]
+fn clock_id_case_insensitive() {
+    for name in [r#""monotonic""#, r#""MONOTONIC""#, r#""MoNoToNiC""#] {
+        assert_eq!(
+            serde_json::from_str::<ClockId>(name).unwrap(),
+            ClockId::Monotonic
+        );
+    }
+    assert!(serde_json::from_str::<ClockId>(r#""nope""#).is_err());
+}
+
+mod nanos {
+    use unix_clock::raw::{timespec_nanos, Timespec};
+
+    fn to_json(t: &Timespec) -> String {
+        timespec_nanos::serialize(t, serde_json::value::Serializer)
+            .unwrap()
+            .to_string()
+    }
+
+    fn from_json(json: &str) -> serde_json::Result<Timespec> {
+        timespec_nanos::deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn json_number_round_trip() {
+        for t in [
+            Timespec::new(-1, 999_999_999),
+            Timespec::new(0, 0),
+            Timespec::new(1_700_000_000, 123_456_789),
+            Timespec::new(i64::MAX / 1_000_000_000, 0),
+        ] {
+            let json = to_json(&t);
+            assert_eq!(json, t.as_nanos().to_string());
+            assert_eq!(from_json(&json).unwrap(), t);
+        }
+    }
+
+    #[test]
+    fn json_string_round_trip() {
+        for t in [Timespec::MIN, Timespec::new(-1, 999_999_999), Timespec::MAX] {
+            let json = format!("\"{}\"", t.as_nanos());
+            assert_eq!(from_json(&json).unwrap(), t);
+        }
+        assert!(from_json(r#""12a""#).is_err());
+    }
+
+    #[test]
+    fn json_number_beyond_u64_rejected() {
+        let nanos = u64::MAX as i128 + 1;
+        assert!(from_json(&nanos.to_string()).is_err());
+        assert_eq!(
+            from_json(&format!("\"{}\"", nanos)).unwrap().as_nanos(),
+            nanos
+        );
+        assert!(from_json(&Timespec::MIN.as_nanos().to_string()).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+//! Kept alone in its own test binary so that the threads below race on the
+//! very first, lazy, lookup of the vDSO functions.
+
+use std::{
+    sync::{Arc, Barrier},
+    thread,
+};
+
+use unix_clock::raw::{ClockId, Timespec};
+
+const THREADS: usize = 32;
+
+#[test]
+fn concurrent_first_reading() {
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let threads = (0..THREADS)
+        .map(|_| {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                let first = Timespec::now(ClockId::Monotonic).unwrap();
+                let res = Timespec::resolution(ClockId::Monotonic).unwrap();
+                let mut last = first;
+                for _ in 0..100 {
+                    let t = Timespec::now(ClockId::Monotonic).unwrap();
+                    assert!(t >= last);
+                    last = t;
+                }
+                assert_eq!(Timespec::resolution(ClockId::Monotonic), Ok(res));
+                (first, last)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let readings = threads
+        .into_iter()
+        .map(|t| t.join().unwrap())
+        .collect::<Vec<_>>();
+    let now = Timespec::now(ClockId::Monotonic).unwrap();
+    for (first, last) in readings {
+        assert!(first > Timespec::zero() && first <= last && last <= now);
+    }
+}